@@ -0,0 +1,419 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::{settle, Card, Contribution, OddChip, PokerError, RankingRules, Settlement};
+
+/// The forced bets at the start of a hand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Blinds {
+    pub small: u64,
+    pub big: u64,
+    /// Posted by every player before the blinds. Antes go into the pot but
+    /// do not count towards a player's bet.
+    pub ante: u64,
+}
+
+/// The betting rounds of a Hold'em hand, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    /// Betting is over and the remaining hands are compared.
+    Showdown,
+}
+
+/// Something the player to act can do.
+///
+/// Bet and raise amounts are what the player's bet comes to for this
+/// street, not the chips added, so after a bet of 20 `Raise(60)` raises by
+/// 40.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Fold,
+    Check,
+    /// Matches the current bet, or puts in every chip left if that is less.
+    Call,
+    /// Opens the betting on a street where no one has bet.
+    Bet(u64),
+    Raise(u64),
+    /// Puts in every chip left, as a call, bet or raise as the amount
+    /// dictates.
+    AllIn,
+}
+
+/// Why an action was refused. The betting state is unchanged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActionError {
+    /// The betting round is complete, or the hand is over.
+    NoPlayerToAct,
+    /// The action is not allowed now, such as checking facing a bet or
+    /// raising when an all-in did not reopen the betting.
+    Illegal(Action),
+    /// A bet or raise outside the amounts allowed.
+    InvalidAmount { action: Action, min: u64, max: u64 },
+    /// The street cannot end while players still have to act.
+    RoundNotComplete,
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ActionError::NoPlayerToAct => f.write_str("no player is to act"),
+            ActionError::Illegal(action) => write!(f, "{:?} is not allowed now", action),
+            ActionError::InvalidAmount { action, min, max } => {
+                write!(f, "{:?} must be between {} and {} chips", action, min, max)
+            }
+            ActionError::RoundNotComplete => f.write_str("the betting round is not complete"),
+        }
+    }
+}
+
+impl Error for ActionError {}
+
+/// The actions open to the player to act.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegalActions {
+    to_call: u64,
+    can_check: bool,
+    raise: Option<RangeInclusive<u64>>,
+    opens: bool,
+}
+
+impl LegalActions {
+    /// Folding is allowed only when facing a bet.
+    pub fn can_fold(&self) -> bool {
+        !self.can_check
+    }
+
+    pub fn can_check(&self) -> bool {
+        self.can_check
+    }
+
+    /// The chips a call adds, which is less than the bet faced when it puts
+    /// the player all in. `None` if there is nothing to call.
+    pub fn call_amount(&self) -> Option<u64> {
+        if self.can_check {
+            None
+        } else {
+            Some(self.to_call)
+        }
+    }
+
+    /// The amounts the player may bet, if no one has bet yet this street.
+    pub fn bet_range(&self) -> Option<RangeInclusive<u64>> {
+        self.raise.clone().filter(|_| self.opens)
+    }
+
+    /// The amounts the player may raise to, if they can raise. An all-in
+    /// for less than a full raise is allowed, as the only amount in range.
+    pub fn raise_range(&self) -> Option<RangeInclusive<u64>> {
+        self.raise.clone().filter(|_| !self.opens)
+    }
+}
+
+/// The betting of one no-limit Texas Hold'em hand, from the blinds to the
+/// showdown.
+///
+/// Players are identified by seat, their position in the stacks given to
+/// [`new`](Betting::new). Each street, [`to_act`](Betting::to_act) names
+/// the player whose turn it is and [`act`](Betting::act) applies their
+/// action, until the round is complete and
+/// [`next_street`](Betting::next_street) moves on.
+///
+/// The rules are the usual ones: the player to the left of the big blind
+/// acts first before the flop and the first player left of the button after
+/// it, with the button posting the small blind heads-up. A bet must be at
+/// least the minimum bet, the big blind unless given otherwise, and a raise
+/// must be by at least that and at least the size of the last full bet or
+/// raise on the street. An all-in for less than that does not reopen the
+/// betting, so players who have already acted may only call or fold, unless
+/// such all-ins together raise them by a full raise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Betting {
+    stacks: Vec<u64>,
+    bets: Vec<u64>,
+    contributed: Vec<u64>,
+    folded: Vec<bool>,
+    /// The current bet when each player last acted this street, or `None`
+    /// if they have not acted.
+    acted_at: Vec<Option<u64>>,
+    button: usize,
+    min_bet: u64,
+    street: Street,
+    current_bet: u64,
+    /// The size of the last full bet or raise this street.
+    min_raise: u64,
+    to_act: Option<usize>,
+}
+
+impl Betting {
+    /// Starts a hand with the given stacks, taking the antes and blinds.
+    /// A player who cannot cover their ante or blind posts what they have
+    /// and is all in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two players or the button is not one
+    /// of their seats.
+    pub fn new(stacks: &[u64], button: usize, blinds: Blinds) -> Betting {
+        Betting::with_min_bet(stacks, button, blinds, blinds.big)
+    }
+
+    /// Starts a hand as [`new`](Betting::new) does, but with bets and
+    /// raises of at least `min_bet` rather than the big blind, as in games
+    /// played with antes alone. The minimum is never less than one chip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two players or the button is not one
+    /// of their seats.
+    pub fn with_min_bet(stacks: &[u64], button: usize, blinds: Blinds, min_bet: u64) -> Betting {
+        let min_bet = min_bet.max(1);
+        let players = stacks.len();
+        assert!(players >= 2, "a hand needs at least two players");
+        assert!(button < players, "the button must be at a seat");
+
+        let mut betting = Betting {
+            stacks: stacks.to_vec(),
+            bets: vec![0; players],
+            contributed: vec![0; players],
+            folded: vec![false; players],
+            acted_at: vec![None; players],
+            button,
+            min_bet,
+            street: Street::Preflop,
+            current_bet: blinds.big,
+            min_raise: min_bet.max(blinds.big),
+            to_act: None,
+        };
+        for seat in 0..players {
+            betting.pay(seat, blinds.ante);
+        }
+        // heads-up the button is the small blind
+        let small = if players == 2 {
+            button
+        } else {
+            (button + 1) % players
+        };
+        let big = (small + 1) % players;
+        betting.post(small, blinds.small);
+        betting.post(big, blinds.big);
+        betting.to_act = betting.next_to_act(big);
+        betting
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    /// The seat of the player to act, or `None` once the round is complete.
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    /// The chips each player has left.
+    pub fn stacks(&self) -> &[u64] {
+        &self.stacks
+    }
+
+    /// Each player's bet on the current street.
+    pub fn bets(&self) -> &[u64] {
+        &self.bets
+    }
+
+    /// Every chip each player has put in this hand, antes included.
+    pub fn contributed(&self) -> &[u64] {
+        &self.contributed
+    }
+
+    pub fn folded(&self) -> &[bool] {
+        &self.folded
+    }
+
+    /// Every chip put in this hand.
+    pub fn pot(&self) -> u64 {
+        self.contributed.iter().sum()
+    }
+
+    /// The bet players must match to stay in on this street.
+    pub fn current_bet(&self) -> u64 {
+        self.current_bet
+    }
+
+    /// Whether no one else is to act on this street.
+    pub fn is_round_complete(&self) -> bool {
+        self.to_act.is_none()
+    }
+
+    /// Whether the hand is over: everyone else has folded, or the showdown
+    /// has been reached.
+    pub fn is_hand_over(&self) -> bool {
+        self.street == Street::Showdown || self.live_players() == 1
+    }
+
+    /// The actions open to the player to act, or `None` if no one is to
+    /// act.
+    pub fn legal_actions(&self) -> Option<LegalActions> {
+        let seat = self.to_act?;
+        let to_call = self.current_bet - self.bets[seat];
+        let all_in = self.bets[seat] + self.stacks[seat];
+        // short all-ins that add up to a full raise reopen the betting too
+        let reopened = self.acted_at[seat].is_none_or(|at| self.current_bet - at >= self.min_raise);
+        let raise = if reopened && all_in > self.current_bet {
+            let min = (self.current_bet + self.min_raise).min(all_in);
+            Some(min..=all_in)
+        } else {
+            None
+        };
+        Some(LegalActions {
+            to_call: to_call.min(self.stacks[seat]),
+            can_check: to_call == 0,
+            raise,
+            opens: self.current_bet == 0,
+        })
+    }
+
+    /// Applies the action of the player to act and passes the turn on.
+    pub fn act(&mut self, action: Action) -> Result<(), ActionError> {
+        let seat = self.to_act.ok_or(ActionError::NoPlayerToAct)?;
+        let legal = self.legal_actions().ok_or(ActionError::NoPlayerToAct)?;
+        let all_in = self.bets[seat] + self.stacks[seat];
+
+        match action {
+            Action::Fold if legal.can_fold() => self.folded[seat] = true,
+            Action::Check if legal.can_check() => {}
+            Action::Call if !legal.can_check() => self.pay_bet(seat, self.current_bet),
+            Action::Bet(to) | Action::Raise(to) => {
+                let range = match action {
+                    Action::Bet(_) => legal.bet_range(),
+                    _ => legal.raise_range(),
+                }
+                .ok_or(ActionError::Illegal(action))?;
+                if !range.contains(&to) {
+                    return Err(ActionError::InvalidAmount {
+                        action,
+                        min: *range.start(),
+                        max: *range.end(),
+                    });
+                }
+                self.raise_to(seat, to);
+            }
+            Action::AllIn if all_in <= self.current_bet => {
+                self.pay_bet(seat, self.current_bet);
+            }
+            Action::AllIn if legal.raise.is_some() => self.raise_to(seat, all_in),
+            _ => return Err(ActionError::Illegal(action)),
+        }
+
+        self.acted_at[seat] = Some(self.current_bet);
+        self.to_act = self.next_to_act(seat);
+        Ok(())
+    }
+
+    /// Ends a complete betting round and starts the next street, returning
+    /// it. Once the hand is over, or no more than one player can still bet,
+    /// the later streets have no one to act.
+    pub fn next_street(&mut self) -> Result<Street, ActionError> {
+        if self.to_act.is_some() {
+            return Err(ActionError::RoundNotComplete);
+        }
+        self.street = match self.street {
+            Street::Preflop => Street::Flop,
+            Street::Flop => Street::Turn,
+            Street::Turn => Street::River,
+            Street::River | Street::Showdown => Street::Showdown,
+        };
+        self.bets.iter_mut().for_each(|bet| *bet = 0);
+        self.acted_at.iter_mut().for_each(|at| *at = None);
+        self.current_bet = 0;
+        self.min_raise = self.min_bet;
+        self.to_act = if self.street == Street::Showdown {
+            None
+        } else {
+            self.next_to_act(self.button)
+        };
+        Ok(self.street)
+    }
+
+    /// Awards the pots, handing the hands to [`settle`]. `hands` holds the
+    /// five cards each player shows down, by seat, and is ignored for
+    /// players who folded.
+    pub fn showdown(
+        &self,
+        hands: &[Option<Vec<Card>>],
+        rules: RankingRules,
+        odd_chip: OddChip,
+    ) -> Result<Settlement, PokerError> {
+        let players: Vec<Contribution> = (0..self.stacks.len())
+            .map(|seat| Contribution {
+                chips: self.contributed[seat],
+                folded: self.folded[seat],
+                hand: hands.get(seat).cloned().flatten(),
+            })
+            .collect();
+        settle(&players, rules, odd_chip)
+    }
+
+    fn live_players(&self) -> usize {
+        self.folded.iter().filter(|folded| !**folded).count()
+    }
+
+    /// Whether `seat` still has to act this street.
+    fn needs_to_act(&self, seat: usize) -> bool {
+        if self.folded[seat] || self.stacks[seat] == 0 || self.live_players() == 1 {
+            return false;
+        }
+        if self.bets[seat] < self.current_bet {
+            return true;
+        }
+        // with no one left to bet against there is nothing to decide
+        let others_can_bet = (0..self.stacks.len())
+            .any(|other| other != seat && !self.folded[other] && self.stacks[other] > 0);
+        others_can_bet && self.acted_at[seat].is_none()
+    }
+
+    /// The first player after `seat`, going round the table, who still has
+    /// to act.
+    fn next_to_act(&self, seat: usize) -> Option<usize> {
+        let players = self.stacks.len();
+        (1..=players)
+            .map(|i| (seat + i) % players)
+            .find(|next| self.needs_to_act(*next))
+    }
+
+    /// Moves up to `chips` from a player's stack into the pot.
+    fn pay(&mut self, seat: usize, chips: u64) -> u64 {
+        let paid = chips.min(self.stacks[seat]);
+        self.stacks[seat] -= paid;
+        self.contributed[seat] += paid;
+        paid
+    }
+
+    /// Posts a blind, which counts towards the player's bet.
+    fn post(&mut self, seat: usize, blind: u64) {
+        self.bets[seat] += self.pay(seat, blind);
+    }
+
+    /// Brings a player's bet up to `to`, or as near as their stack allows.
+    fn pay_bet(&mut self, seat: usize, to: u64) {
+        let owed = to.saturating_sub(self.bets[seat]);
+        self.bets[seat] += self.pay(seat, owed);
+    }
+
+    fn raise_to(&mut self, seat: usize, to: u64) {
+        let raise = to - self.current_bet;
+        // only a full raise sets the next minimum
+        if raise >= self.min_raise {
+            self.min_raise = raise;
+        }
+        self.current_bet = to;
+        self.pay_bet(seat, to);
+    }
+}
@@ -0,0 +1,338 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::PokerError;
+
+/// The rank of a card, from two up to ace.
+///
+/// Ranks compare by their value in high-hand poker, so aces are high. The
+/// discriminant of each variant is that value, which is also what
+/// [`Rank::value`] and `TryFrom<u8>` use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two = 2,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    /// All ranks, lowest first.
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    /// The numeric value of the rank: 2 to 10 for pip cards, then 11 for a
+    /// jack up to 14 for an ace.
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    /// The rank written out in English, such as `"Queen"` or `"Seven"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+            Rank::Ace => "Ace",
+        }
+    }
+
+    /// The plural of [`name`](Rank::name), such as `"Queens"` or `"Sixes"`.
+    pub fn plural_name(self) -> &'static str {
+        match self {
+            Rank::Two => "Twos",
+            Rank::Three => "Threes",
+            Rank::Four => "Fours",
+            Rank::Five => "Fives",
+            Rank::Six => "Sixes",
+            Rank::Seven => "Sevens",
+            Rank::Eight => "Eights",
+            Rank::Nine => "Nines",
+            Rank::Ten => "Tens",
+            Rank::Jack => "Jacks",
+            Rank::Queen => "Queens",
+            Rank::King => "Kings",
+            Rank::Ace => "Aces",
+        }
+    }
+}
+
+impl TryFrom<u8> for Rank {
+    type Error = PokerError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            2..=14 => Ok(Rank::ALL[usize::from(value - 2)]),
+            _ => Err(PokerError::InvalidRank {
+                offset: 0,
+                token: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for Rank {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rank = match s {
+            "2" => Rank::Two,
+            "3" => Rank::Three,
+            "4" => Rank::Four,
+            "5" => Rank::Five,
+            "6" => Rank::Six,
+            "7" => Rank::Seven,
+            "8" => Rank::Eight,
+            "9" => Rank::Nine,
+            "10" => Rank::Ten,
+            "J" => Rank::Jack,
+            "Q" => Rank::Queen,
+            "K" => Rank::King,
+            "A" => Rank::Ace,
+            _ => {
+                return Err(PokerError::InvalidRank {
+                    offset: 0,
+                    token: s.to_string(),
+                })
+            }
+        };
+        Ok(rank)
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rank::Jack => f.write_str("J"),
+            Rank::Queen => f.write_str("Q"),
+            Rank::King => f.write_str("K"),
+            Rank::Ace => f.write_str("A"),
+            pip => write!(f, "{}", pip.value()),
+        }
+    }
+}
+
+/// The suit of a card.
+///
+/// Suits have no value in standard poker, but they are ordered
+/// alphabetically (clubs, diamonds, hearts, spades) so that cards have a
+/// total order. `TryFrom<u8>` accepts that position, 0 to 3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Club,
+    Diamond,
+    Heart,
+    Spade,
+}
+
+impl Suit {
+    /// All suits, in order.
+    pub const ALL: [Suit; 4] = [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade];
+}
+
+impl TryFrom<u8> for Suit {
+    type Error = PokerError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Suit::ALL
+            .get(usize::from(value))
+            .copied()
+            .ok_or_else(|| PokerError::InvalidSuit {
+                offset: 0,
+                token: value.to_string(),
+            })
+    }
+}
+
+impl FromStr for Suit {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "C" => Ok(Suit::Club),
+            "D" => Ok(Suit::Diamond),
+            "H" => Ok(Suit::Heart),
+            "S" => Ok(Suit::Spade),
+            _ => Err(PokerError::InvalidSuit {
+                offset: 0,
+                token: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Suit::Club => "C",
+            Suit::Diamond => "D",
+            Suit::Heart => "H",
+            Suit::Spade => "S",
+        })
+    }
+}
+
+/// A single playing card, written as its rank followed by its suit, e.g.
+/// `10H` or `AS`.
+///
+/// Cards order by rank first and suit second. `TryFrom<u8>` and
+/// [`Card::index`] number the 52 cards in that order, from `2C` as 0 up to
+/// `AS` as 51.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Card {
+    rank: Rank,
+    suit: Suit,
+}
+
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        Card { rank, suit }
+    }
+
+    pub fn rank(self) -> Rank {
+        self.rank
+    }
+
+    pub fn suit(self) -> Suit {
+        self.suit
+    }
+
+    /// Position of the card in a sorted deck, 0 to 51.
+    pub fn index(self) -> u8 {
+        (self.rank.value() - 2) * 4 + self.suit as u8
+    }
+}
+
+impl TryFrom<u8> for Card {
+    type Error = PokerError;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        if index >= 52 {
+            return Err(PokerError::InvalidCardIndex {
+                offset: 0,
+                token: index.to_string(),
+            });
+        }
+        Ok(Card {
+            rank: Rank::ALL[usize::from(index / 4)],
+            suit: Suit::ALL[usize::from(index % 4)],
+        })
+    }
+}
+
+impl FromStr for Card {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_card(s, 0)
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)
+    }
+}
+
+/// Parses a single card token found at `offset` in some larger string.
+pub(crate) fn parse_card(card_str: &str, offset: usize) -> Result<Card, PokerError> {
+    let suit_start = match card_str.char_indices().next_back() {
+        Some((i, _)) => i,
+        None => {
+            return Err(PokerError::InvalidSuit {
+                offset,
+                token: card_str.to_string(),
+            })
+        }
+    };
+    let (rank_str, suit_str) = card_str.split_at(suit_start);
+
+    let suit = suit_str
+        .parse::<Suit>()
+        .map_err(|_| PokerError::InvalidSuit {
+            offset,
+            token: card_str.to_string(),
+        })?;
+    let rank = rank_str
+        .parse::<Rank>()
+        .map_err(|_| PokerError::InvalidRank {
+            offset,
+            token: card_str.to_string(),
+        })?;
+
+    Ok(Card { rank, suit })
+}
+
+/// A card that may be a joker, as dealt from a deck with jokers in it.
+///
+/// Jokers are written `JK`, or `XX` as some tools do, and display as `JK`.
+/// Any other notation is parsed as a [`Card`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlayingCard {
+    Card(Card),
+    Joker,
+}
+
+impl From<Card> for PlayingCard {
+    fn from(card: Card) -> PlayingCard {
+        PlayingCard::Card(card)
+    }
+}
+
+impl FromStr for PlayingCard {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_playing_card(s, 0)
+    }
+}
+
+impl fmt::Display for PlayingCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlayingCard::Card(card) => card.fmt(f),
+            PlayingCard::Joker => f.write_str("JK"),
+        }
+    }
+}
+
+/// Parses a single card or joker token found at `offset` in some larger
+/// string.
+pub(crate) fn parse_playing_card(card_str: &str, offset: usize) -> Result<PlayingCard, PokerError> {
+    match card_str {
+        "JK" | "XX" => Ok(PlayingCard::Joker),
+        _ => parse_card(card_str, offset).map(PlayingCard::Card),
+    }
+}
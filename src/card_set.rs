@@ -0,0 +1,261 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+use std::str::FromStr;
+
+use crate::card::parse_card;
+use crate::hand::{check_card_count, evaluate_five, join, tokenize};
+use crate::lookup::rank_suit_masks;
+use crate::{best_hand, BestHand, Card, EvaluatedHand, PokerError};
+
+const ALL_CARDS: u64 = (1 << 52) - 1;
+
+/// A set of cards from a single deck, stored as one bit per card.
+///
+/// Set operations are single instructions, which makes this the cheap way to
+/// track which cards are dead, dealt or still in the deck. Bit `i` is the
+/// card with [`Card::index`] `i`, and iteration follows that order.
+///
+/// Sets are written as whitespace separated cards, in the same notation as
+/// hands.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub fn new() -> CardSet {
+        CardSet(0)
+    }
+
+    /// All 52 cards.
+    pub fn full() -> CardSet {
+        CardSet(ALL_CARDS)
+    }
+
+    /// The set with the given bits. Bits above the 52nd are ignored.
+    pub fn from_bits(bits: u64) -> CardSet {
+        CardSet(bits & ALL_CARDS)
+    }
+
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    pub fn contains(self, card: Card) -> bool {
+        self.0 & bit(card) != 0
+    }
+
+    /// Adds a card, returning whether it was newly added.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let added = !self.contains(card);
+        self.0 |= bit(card);
+        added
+    }
+
+    /// Removes a card, returning whether it was present.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !bit(card);
+        present
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// The cards in `self` that are not in `other`.
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// The cards of the deck that are not in this set.
+    pub fn complement(self) -> CardSet {
+        CardSet(!self.0 & ALL_CARDS)
+    }
+
+    pub fn is_disjoint(self, other: CardSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    pub fn is_subset(self, other: CardSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn iter(self) -> Iter {
+        Iter(self.0)
+    }
+
+    pub fn to_vec(self) -> Vec<Card> {
+        self.iter().collect()
+    }
+
+    /// Evaluates a set of exactly five cards.
+    pub fn evaluate(self) -> Result<EvaluatedHand, PokerError> {
+        let cards = self.to_vec();
+        check_card_count(cards.len(), 5..=5, || join(&cards))?;
+        Ok(evaluate_five(&cards))
+    }
+
+    /// Picks the best five-card hand from a set of five to seven cards, as
+    /// [`best_hand`] does.
+    pub fn best_hand(self) -> Result<BestHand, PokerError> {
+        best_hand(&self.to_vec())
+    }
+
+    /// Ranks a set of five to seven cards, as [`hand_rank`](crate::hand_rank)
+    /// does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than five or more than seven cards.
+    pub fn hand_rank(self) -> u16 {
+        let len = self.len();
+        assert!(
+            (5..=7).contains(&len),
+            "cannot rank a hand of {} cards",
+            len
+        );
+        let mut suit_masks = [0; 4];
+        let mut counts = [0; 13];
+        for card in self {
+            let rank = usize::from(card.rank().value() - 2);
+            suit_masks[card.suit() as usize] |= 1 << rank;
+            counts[rank] += 1;
+        }
+        rank_suit_masks(len, &suit_masks, &counts)
+    }
+}
+
+fn bit(card: Card) -> u64 {
+    1 << card.index()
+}
+
+/// Iterates over the cards of a [`CardSet`] in index order.
+#[derive(Clone, Debug)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Card::try_from(index).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> CardSet {
+        let mut set = CardSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> CardSet {
+        CardSet(bit(card))
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        self.complement()
+    }
+}
+
+impl FromStr for CardSet {
+    type Err = PokerError;
+
+    /// Parses whitespace separated cards. Unlike a hand, a set may hold any
+    /// number of cards, including none, but no card may appear twice.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = CardSet::new();
+        for (offset, token) in tokenize(s) {
+            if !set.insert(parse_card(token, offset)?) {
+                return Err(PokerError::DuplicateCard {
+                    offset,
+                    token: token.to_string(),
+                });
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, card) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", card)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::convert::TryFrom;
+
+use crate::rng::Rng;
+use crate::{Card, PlayingCard, Rank};
+
+/// A deck of cards to deal from, top card first.
+///
+/// Decks start in a fixed order; [`shuffle`](Deck::shuffle) puts them in an
+/// order determined entirely by its seed, so a simulation or test can deal
+/// the same hands every time it runs. The cards are usually [`Card`]s, or
+/// [`PlayingCard`]s for a deck with jokers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deck<C = Card> {
+    cards: Vec<C>,
+    burned: Vec<C>,
+}
+
+impl Deck {
+    /// The standard 52-card deck, in [`Card::index`] order.
+    pub fn new() -> Deck {
+        Deck::from_cards((0..52).filter_map(|i| Card::try_from(i).ok()).collect())
+    }
+
+    /// The 36-card deck used for short-deck poker, without the twos to
+    /// fives.
+    pub fn short() -> Deck {
+        let mut deck = Deck::new();
+        deck.cards.retain(|card| card.rank() >= Rank::Six);
+        deck
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Deck {
+        Deck::new()
+    }
+}
+
+impl Deck<PlayingCard> {
+    /// The standard 52 cards followed by `jokers` jokers.
+    pub fn with_jokers(jokers: usize) -> Deck<PlayingCard> {
+        let mut cards: Vec<PlayingCard> = Deck::new().cards.into_iter().map(Into::into).collect();
+        cards.extend(std::iter::repeat_n(PlayingCard::Joker, jokers));
+        Deck::from_cards(cards)
+    }
+}
+
+impl<C: Copy + PartialEq> Deck<C> {
+    /// A deck holding exactly `cards`, the first of them on top.
+    pub fn from_cards(cards: Vec<C>) -> Deck<C> {
+        Deck {
+            cards,
+            burned: Vec::new(),
+        }
+    }
+
+    /// Shuffles the cards left in the deck. The same seed always gives the
+    /// same order.
+    pub fn shuffle(&mut self, seed: u64) {
+        let len = self.cards.len();
+        Rng::new(seed).partial_shuffle(&mut self.cards, len);
+    }
+
+    /// The cards left in the deck, top card first.
+    pub fn cards(&self) -> &[C] {
+        &self.cards
+    }
+
+    /// The cards burned so far, in the order they were burned.
+    pub fn burned(&self) -> &[C] {
+        &self.burned
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Deals the top card, if there is one.
+    pub fn deal_one(&mut self) -> Option<C> {
+        if self.cards.is_empty() {
+            return None;
+        }
+        Some(self.cards.remove(0))
+    }
+
+    /// Deals the top `n` cards, or nothing if fewer than `n` are left.
+    pub fn deal(&mut self, n: usize) -> Option<Vec<C>> {
+        if n > self.cards.len() {
+            return None;
+        }
+        Some(self.cards.drain(..n).collect())
+    }
+
+    /// Moves the top card face down to the burned cards, returning it.
+    pub fn burn(&mut self) -> Option<C> {
+        let card = self.deal_one()?;
+        self.burned.push(card);
+        Some(card)
+    }
+
+    /// Takes a known card out of the deck, such as one already seen or dealt
+    /// elsewhere. Returns whether it was in the deck; for a card the deck
+    /// holds several of, such as a joker, one of them is removed.
+    pub fn remove(&mut self, card: C) -> bool {
+        match self.cards.iter().position(|c| *c == card) {
+            Some(i) => {
+                self.cards.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+}
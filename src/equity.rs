@@ -0,0 +1,466 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use crate::hand::{check_card_count, check_distinct, for_each_combination, join};
+use crate::rng::Rng;
+use crate::{Card, CardSet, PokerError, Range, Rank, Suit};
+
+/// How long a simulation runs for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Budget {
+    /// Exactly this many run-outs, so results depend only on the seed.
+    Iterations(u64),
+    /// As many run-outs as fit in this time. Results are not reproducible,
+    /// as the number of run-outs depends on the machine.
+    Time(Duration),
+}
+
+/// How often one player's hand won, tied and lost across a number of
+/// run-outs.
+///
+/// A tie is any run-out where the player shared the pot; the player's
+/// [`equity`](Equity::equity) counts it as the fraction of the pot they won.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Equity {
+    trials: u64,
+    wins: u64,
+    ties: u64,
+    share: f64,
+    share_squares: f64,
+}
+
+impl Equity {
+    pub fn trials(&self) -> u64 {
+        self.trials
+    }
+
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    pub fn ties(&self) -> u64 {
+        self.ties
+    }
+
+    pub fn losses(&self) -> u64 {
+        self.trials - self.wins - self.ties
+    }
+
+    /// The fraction of run-outs won outright.
+    pub fn win(&self) -> f64 {
+        self.fraction(self.wins)
+    }
+
+    /// The fraction of run-outs where the pot was split.
+    pub fn tie(&self) -> f64 {
+        self.fraction(self.ties)
+    }
+
+    /// The fraction of run-outs lost.
+    pub fn lose(&self) -> f64 {
+        self.fraction(self.losses())
+    }
+
+    /// The average share of the pot won, counting a pot split between `n`
+    /// players as `1 / n` for each of them.
+    pub fn equity(&self) -> f64 {
+        if self.trials == 0 {
+            return 0.0;
+        }
+        self.share / self.trials as f64
+    }
+
+    /// The standard error of [`equity`](Equity::equity) as an estimate of
+    /// the true equity, from the spread of the shares won. Zero for fewer
+    /// than two run-outs.
+    pub fn std_error(&self) -> f64 {
+        if self.trials < 2 {
+            return 0.0;
+        }
+        let n = self.trials as f64;
+        let mean = self.share / n;
+        let variance = (self.share_squares - n * mean * mean) / (n - 1.0);
+        (variance.max(0.0) / n).sqrt()
+    }
+
+    fn fraction(&self, count: u64) -> f64 {
+        if self.trials == 0 {
+            return 0.0;
+        }
+        count as f64 / self.trials as f64
+    }
+
+    /// Records one run-out in which the player won a `1 / winners` share,
+    /// or nothing if `winners` is zero.
+    pub(crate) fn record(&mut self, winners: usize) {
+        self.trials += 1;
+        let share = match winners {
+            0 => return,
+            1 => {
+                self.wins += 1;
+                1.0
+            }
+            n => {
+                self.ties += 1;
+                1.0 / n as f64
+            }
+        };
+        self.share += share;
+        self.share_squares += share * share;
+    }
+}
+
+/// Estimates each Texas Hold'em player's equity by dealing out the rest of
+/// the board at random.
+///
+/// Each player holds two cards. The board holds up to five cards already
+/// dealt, and `dead` cards are known to be out of the deck. The same `seed`
+/// and an [`Iterations`](Budget::Iterations) budget always give the same
+/// result. The returned equities are in the same order as `players`.
+///
+/// Errors use the position of the offending card in the players' cards,
+/// then the board, then the dead cards as their offset.
+pub fn monte_carlo_equity<P: AsRef<[Card]>>(
+    players: &[P],
+    board: &[Card],
+    dead: &[Card],
+    budget: Budget,
+    seed: u64,
+) -> Result<Vec<Equity>, PokerError> {
+    let known = check_deal(players, 2..=2, board, dead)?;
+    let holes: Vec<CardSet> = players
+        .iter()
+        .map(|p| p.as_ref().iter().copied().collect())
+        .collect();
+    let board_set: CardSet = board.iter().copied().collect();
+    let mut live = known.complement().to_vec();
+    let needed = 5 - board.len();
+
+    let mut rng = Rng::new(seed);
+    let mut equities = vec![Equity::default(); players.len()];
+    let mut ranks = vec![0; players.len()];
+    let mut run_out = |rng: &mut Rng| {
+        rng.partial_shuffle(&mut live, needed);
+        let full_board = live[..needed]
+            .iter()
+            .fold(board_set, |set, card| set | CardSet::from(*card));
+        for (rank, hole) in ranks.iter_mut().zip(&holes) {
+            *rank = (full_board | *hole).hand_rank();
+        }
+        record_showdown(&mut equities, &ranks);
+    };
+
+    run(budget, || run_out(&mut rng));
+    Ok(equities)
+}
+
+/// Estimates the equity of each of several weighted ranges against the
+/// others by dealing random hands from them and then the rest of the board.
+///
+/// Hands are dealt in proportion to their weights, and deals in which two
+/// hands share a card are thrown away, so hands that conflict with likely
+/// holdings of other players are dealt less often, as at a real table.
+/// Combinations holding a board or dead card are never dealt. The budget,
+/// seed and results are as for [`monte_carlo_equity`], with one equity per
+/// range.
+///
+/// Errors about the board and dead cards are those of
+/// [`monte_carlo_equity`]. A range with no hand that can be dealt alongside
+/// the others gives [`EmptyHand`](PokerError::EmptyHand) with the index of
+/// the range as its offset.
+pub fn range_equity(
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+    budget: Budget,
+    seed: u64,
+) -> Result<Vec<Equity>, PokerError> {
+    let known = check_deal::<&[Card]>(&[], 2..=2, board, dead)?;
+    let combos: Vec<Vec<(CardSet, f64)>> = ranges
+        .iter()
+        .map(|range| {
+            let mut total = 0.0;
+            range
+                .without(known)
+                .iter()
+                .map(|(combo, weight)| {
+                    total += weight;
+                    (combo.iter().copied().collect(), total)
+                })
+                .collect()
+        })
+        .collect();
+    check_enough_cards(&combos, known)?;
+    check_compatible(&combos, 0, known, &mut HashMap::new())?;
+
+    let board_set: CardSet = board.iter().copied().collect();
+    let live = known.complement().to_vec();
+
+    let mut rng = Rng::new(seed);
+    let mut equities = vec![Equity::default(); ranges.len()];
+    let mut holes = vec![CardSet::new(); ranges.len()];
+    let mut ranks = vec![0; ranges.len()];
+    let mut run_out = |rng: &mut Rng| {
+        let mut dealt = deal_hands(&combos, &mut holes, known, rng);
+        let mut full_board = board_set;
+        while full_board.len() < 5 {
+            let card = live[rng.below(live.len())];
+            if dealt.insert(card) {
+                full_board.insert(card);
+            }
+        }
+        for (rank, hole) in ranks.iter_mut().zip(&holes) {
+            *rank = (full_board | *hole).hand_rank();
+        }
+        record_showdown(&mut equities, &ranks);
+    };
+    run(budget, || run_out(&mut rng));
+    Ok(equities)
+}
+
+/// Deals one hand from each range into `holes`, weighted by the cumulative
+/// weights in `combos`, until no two share a card. Returns every card known
+/// or dealt.
+fn deal_hands(
+    combos: &[Vec<(CardSet, f64)>],
+    holes: &mut [CardSet],
+    known: CardSet,
+    rng: &mut Rng,
+) -> CardSet {
+    'deal: loop {
+        let mut dealt = known;
+        for (hole, combos) in holes.iter_mut().zip(combos) {
+            let total = combos.last().map_or(0.0, |(_, total)| *total);
+            let target = rng.next_f64() * total;
+            let i = combos.partition_point(|(_, cumulative)| *cumulative <= target);
+            *hole = combos[i.min(combos.len() - 1)].0;
+            if !hole.is_disjoint(dealt) {
+                continue 'deal;
+            }
+            dealt = dealt | *hole;
+        }
+        return dealt;
+    }
+}
+
+/// Rules out deals that plainly cannot be made before searching for one:
+/// more hands than the live cards can make, or ranges that between them
+/// need more cards of some rank or suit than are left.
+fn check_enough_cards(combos: &[Vec<(CardSet, f64)>], known: CardSet) -> Result<(), PokerError> {
+    let live = known.complement();
+    if combos.len() * 2 > live.len() {
+        return Err(no_hand(live.len() / 2));
+    }
+    let ranks = Rank::ALL
+        .map(|rank| -> CardSet { live.iter().filter(|card| card.rank() == rank).collect() });
+    let suits = Suit::ALL
+        .map(|suit| -> CardSet { live.iter().filter(|card| card.suit() == suit).collect() });
+    for cards in ranks.iter().chain(&suits) {
+        let mut needed = 0;
+        for (i, range) in combos.iter().enumerate() {
+            needed += range
+                .iter()
+                .map(|(hole, _)| (*hole & *cards).len())
+                .min()
+                .unwrap_or(0);
+            if needed > cards.len() {
+                return Err(no_hand(i));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that one hand from each range from `first` on can be dealt
+/// without sharing a card with each other or with `dealt`. The outcome for
+/// each set of cards dealt is remembered in `failed`, so that the search
+/// does not repeat itself.
+fn check_compatible(
+    combos: &[Vec<(CardSet, f64)>],
+    first: usize,
+    dealt: CardSet,
+    failed: &mut HashMap<CardSet, PokerError>,
+) -> Result<(), PokerError> {
+    let Some(range) = combos.get(first) else {
+        return Ok(());
+    };
+    // every range before `first` put two cards into `dealt`, so it alone
+    // says how far the search has got
+    if let Some(err) = failed.get(&dealt) {
+        return Err(err.clone());
+    }
+    let mut deepest = no_hand(first);
+    for (hole, _) in range {
+        if hole.is_disjoint(dealt) {
+            match check_compatible(combos, first + 1, dealt | *hole, failed) {
+                Ok(()) => return Ok(()),
+                Err(err) => deepest = err,
+            }
+        }
+    }
+    failed.insert(dealt, deepest.clone());
+    Err(deepest)
+}
+
+fn no_hand(range: usize) -> PokerError {
+    PokerError::EmptyHand {
+        offset: range,
+        token: String::new(),
+    }
+}
+
+/// Calls `run_out` as many times as `budget` allows.
+fn run(budget: Budget, mut run_out: impl FnMut()) {
+    match budget {
+        Budget::Iterations(iterations) => {
+            for _ in 0..iterations {
+                run_out();
+            }
+        }
+        Budget::Time(limit) => {
+            // checking the clock is slow next to a run-out, so do it in batches
+            let start = Instant::now();
+            while start.elapsed() < limit {
+                for _ in 0..256 {
+                    run_out();
+                }
+            }
+        }
+    }
+}
+
+/// Works out each Texas Hold'em player's exact equity by dealing every
+/// possible completion of the board once.
+///
+/// The arguments and errors are those of [`monte_carlo_equity`]. Hands are
+/// ranked and ties split exactly as [`winning_hands`](crate::winning_hands)
+/// would, so every [`Equity`] holds exact win and tie counts. Heads-up
+/// before the flop this is 1,712,304 boards.
+pub fn exhaustive_equity<P: AsRef<[Card]>>(
+    players: &[P],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<Equity>, PokerError> {
+    let known = check_deal(players, 2..=2, board, dead)?;
+    let holes: Vec<CardSet> = players
+        .iter()
+        .map(|p| p.as_ref().iter().copied().collect())
+        .collect();
+    Ok(enumerate(
+        players.len(),
+        board,
+        known,
+        |full_board, ranks| {
+            for (rank, hole) in ranks.iter_mut().zip(&holes) {
+                *rank = (full_board | *hole).hand_rank();
+            }
+        },
+    ))
+}
+
+/// Works out each Omaha player's exact equity by dealing every possible
+/// completion of the board once.
+///
+/// Each player holds four to six cards and must play exactly two of them
+/// with three from the board, as in [`evaluate_omaha`](crate::evaluate_omaha).
+/// Otherwise the arguments, errors and results are those of
+/// [`exhaustive_equity`].
+pub fn exhaustive_omaha_equity<P: AsRef<[Card]>>(
+    players: &[P],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<Equity>, PokerError> {
+    let known = check_deal(players, 4..=6, board, dead)?;
+    let pairs: Vec<Vec<CardSet>> = players
+        .iter()
+        .map(|p| {
+            let mut pairs = Vec::new();
+            for_each_combination(p.as_ref(), 2, |pair| {
+                pairs.push(pair.iter().copied().collect())
+            });
+            pairs
+        })
+        .collect();
+    let mut triples = Vec::with_capacity(10);
+    Ok(enumerate(
+        players.len(),
+        board,
+        known,
+        |full_board, ranks| {
+            triples.clear();
+            for_each_combination(&full_board.to_vec(), 3, |triple| {
+                triples.push(triple.iter().copied().collect::<CardSet>())
+            });
+            for (rank, pairs) in ranks.iter_mut().zip(&pairs) {
+                *rank = pairs
+                    .iter()
+                    .flat_map(|pair| {
+                        triples
+                            .iter()
+                            .map(move |triple| (*pair | *triple).hand_rank())
+                    })
+                    .max()
+                    .unwrap_or(0);
+            }
+        },
+    ))
+}
+
+/// Deals every completion of `board` from the cards that are not `known`
+/// and records the showdown on each. `rank` fills in each player's
+/// [`hand_rank`](crate::hand_rank) on a complete board.
+fn enumerate(
+    players: usize,
+    board: &[Card],
+    known: CardSet,
+    mut rank: impl FnMut(CardSet, &mut [u16]),
+) -> Vec<Equity> {
+    let board_set: CardSet = board.iter().copied().collect();
+    let live = known.complement().to_vec();
+
+    let mut equities = vec![Equity::default(); players];
+    let mut ranks = vec![0; players];
+    for_each_combination(&live, 5 - board.len(), |extra| {
+        let full_board = board_set | extra.iter().copied().collect();
+        rank(full_board, &mut ranks);
+        record_showdown(&mut equities, &ranks);
+    });
+    equities
+}
+
+/// Records a showdown in which the players with the highest rank split the
+/// pot.
+fn record_showdown(equities: &mut [Equity], ranks: &[u16]) {
+    let best = ranks.iter().copied().max().unwrap_or(0);
+    let winners = ranks.iter().filter(|r| **r == best).count();
+    for (equity, rank) in equities.iter_mut().zip(ranks) {
+        equity.record(if *rank == best { winners } else { 0 });
+    }
+}
+
+/// Checks that every player holds a number of cards in `hole`, the board
+/// holds at most five, no card is known twice and enough cards are left to
+/// complete the board. Returns every known card.
+pub(crate) fn check_deal<P: AsRef<[Card]>>(
+    players: &[P],
+    hole: RangeInclusive<usize>,
+    board: &[Card],
+    dead: &[Card],
+) -> Result<CardSet, PokerError> {
+    let known: Vec<Card> = players
+        .iter()
+        .flat_map(|p| p.as_ref().iter())
+        .chain(board)
+        .chain(dead)
+        .copied()
+        .collect();
+    check_distinct(&known)?;
+    for player in players {
+        let cards = player.as_ref();
+        check_card_count(cards.len(), hole.clone(), || join(cards))?;
+    }
+    check_card_count(board.len(), 0..=5, || join(board))?;
+    check_card_count(known.len(), 0..=52 - (5 - board.len()), || join(&known))?;
+    Ok(known.into_iter().collect())
+}
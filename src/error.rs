@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::fmt;
+
+/// Everything that can go wrong while reading a hand.
+///
+/// Each variant carries the byte offset of the offending token within the
+/// string that was being parsed, along with the token itself, so that callers
+/// can point users at exactly what was wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PokerError {
+    /// The rank part of a card (everything before the suit) is not one of
+    /// `2`-`10`, `J`, `Q`, `K` or `A`.
+    InvalidRank { offset: usize, token: String },
+    /// The last character of a card is not one of `C`, `D`, `H` or `S`.
+    InvalidSuit { offset: usize, token: String },
+    /// A hand, or a group of cards such as hole cards or a board, does not
+    /// contain the number of cards the game calls for. The token is the whole
+    /// group.
+    WrongCardCount {
+        offset: usize,
+        token: String,
+        count: usize,
+    },
+    /// The hand contains no cards at all.
+    EmptyHand { offset: usize, token: String },
+    /// The same card appears more than once in a single hand. The offset
+    /// points at the second occurrence.
+    DuplicateCard { offset: usize, token: String },
+    /// An entry of a hand range, such as `AKs` or `TT+:0.5`, is not valid
+    /// range notation. The token is the whole entry.
+    InvalidRange { offset: usize, token: String },
+    /// A line of a hand history is not in the expected format, or names a
+    /// player who is not seated. The token is the offending line, or the
+    /// amount within it.
+    InvalidHistory { offset: usize, token: String },
+    /// A card index is not between 0 and 51. The token is the index; there
+    /// is no string, so the offset is 0.
+    InvalidCardIndex { offset: usize, token: String },
+}
+
+impl PokerError {
+    /// Byte offset of the offending token within the parsed string.
+    pub fn offset(&self) -> usize {
+        match self {
+            PokerError::InvalidRank { offset, .. }
+            | PokerError::InvalidSuit { offset, .. }
+            | PokerError::WrongCardCount { offset, .. }
+            | PokerError::EmptyHand { offset, .. }
+            | PokerError::DuplicateCard { offset, .. }
+            | PokerError::InvalidRange { offset, .. }
+            | PokerError::InvalidHistory { offset, .. }
+            | PokerError::InvalidCardIndex { offset, .. } => *offset,
+        }
+    }
+
+    /// The offending token.
+    pub fn token(&self) -> &str {
+        match self {
+            PokerError::InvalidRank { token, .. }
+            | PokerError::InvalidSuit { token, .. }
+            | PokerError::WrongCardCount { token, .. }
+            | PokerError::EmptyHand { token, .. }
+            | PokerError::DuplicateCard { token, .. }
+            | PokerError::InvalidRange { token, .. }
+            | PokerError::InvalidHistory { token, .. }
+            | PokerError::InvalidCardIndex { token, .. } => token,
+        }
+    }
+}
+
+impl fmt::Display for PokerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PokerError::InvalidRank { offset, token } => {
+                write!(f, "invalid rank in card {:?} at byte {}", token, offset)
+            }
+            PokerError::InvalidSuit { offset, token } => {
+                write!(f, "invalid suit in card {:?} at byte {}", token, offset)
+            }
+            PokerError::WrongCardCount {
+                offset,
+                token,
+                count,
+            } => write!(
+                f,
+                "wrong number of cards ({}) in {:?} at byte {}",
+                count, token, offset
+            ),
+            PokerError::EmptyHand { offset, token } => {
+                write!(f, "empty hand {:?} at byte {}", token, offset)
+            }
+            PokerError::DuplicateCard { offset, token } => {
+                write!(f, "duplicate card {:?} at byte {}", token, offset)
+            }
+            PokerError::InvalidRange { offset, token } => {
+                write!(f, "invalid range {:?} at byte {}", token, offset)
+            }
+            PokerError::InvalidHistory { offset, token } => {
+                write!(f, "invalid hand history {:?} at byte {}", token, offset)
+            }
+            PokerError::InvalidCardIndex { token, .. } => {
+                write!(f, "card index {} is not between 0 and 51", token)
+            }
+        }
+    }
+}
+
+impl Error for PokerError {}
@@ -0,0 +1,170 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::{EvaluatedHand, HandType, Rank};
+
+/// Why one hand beats another, or why they tie, under standard high-hand
+/// rules.
+///
+/// Returned by [`explain`]. Its `Display` spells the reason out, such as
+/// `"Flush beats straight"` or
+/// `"both two pair Jacks and Eights; Queen kicker beats Two"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    ordering: Ordering,
+    reason: Reason,
+    shared: Vec<Rank>,
+}
+
+/// What decided a comparison between two hands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// The hands are of different categories.
+    Category { winner: HandType, loser: HandType },
+    /// The hands are of the same category and were decided by the ranks at
+    /// `position` in their [`tie_breaker`](EvaluatedHand::tie_breaker)s, the
+    /// first place they differ.
+    Rank {
+        hand_type: HandType,
+        position: usize,
+        winner: Rank,
+        loser: Rank,
+    },
+    /// The hands are equal and split the pot.
+    Tie { hand_type: HandType },
+}
+
+impl Explanation {
+    /// `Greater` if the first hand wins, as for
+    /// [`RankingRules::compare`](crate::RankingRules::compare).
+    pub fn ordering(&self) -> Ordering {
+        self.ordering
+    }
+
+    pub fn reason(&self) -> Reason {
+        self.reason
+    }
+
+    /// The tie-breaker ranks both hands share ahead of the deciding one, or
+    /// all of them for a tie. Empty when the categories differ.
+    pub fn shared(&self) -> &[Rank] {
+        &self.shared
+    }
+}
+
+/// Compares two hands under standard high-hand rules and explains the
+/// result: either the hands are of different categories, or the first rank
+/// that differs between their tie breakers decides, or they tie.
+pub fn explain(a: &EvaluatedHand, b: &EvaluatedHand) -> Explanation {
+    let ordering = a.cmp(b);
+    let (winner, loser) = match ordering {
+        Ordering::Less => (b, a),
+        _ => (a, b),
+    };
+    let hand_type = winner.hand_type();
+    if hand_type != loser.hand_type() {
+        return Explanation {
+            ordering,
+            reason: Reason::Category {
+                winner: hand_type,
+                loser: loser.hand_type(),
+            },
+            shared: Vec::new(),
+        };
+    }
+
+    let ranks = winner.tie_breaker();
+    match ranks
+        .iter()
+        .zip(loser.tie_breaker())
+        .position(|(w, l)| w != l)
+    {
+        Some(position) => Explanation {
+            ordering,
+            reason: Reason::Rank {
+                hand_type,
+                position,
+                winner: ranks[position],
+                loser: loser.tie_breaker()[position],
+            },
+            shared: ranks[..position].to_vec(),
+        },
+        None => Explanation {
+            ordering,
+            reason: Reason::Tie { hand_type },
+            shared: ranks.to_vec(),
+        },
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.reason {
+            Reason::Category { winner, loser } => {
+                write!(f, "{} beats {}", winner, loser.to_string().to_lowercase())
+            }
+            Reason::Rank {
+                hand_type,
+                position,
+                winner,
+                loser,
+            } => {
+                if position > 0 {
+                    write!(f, "both ")?;
+                    write_shared(f, hand_type, &self.shared)?;
+                    write!(f, "; ")?;
+                } else {
+                    write!(f, "{}: ", hand_type)?;
+                }
+                if position < groups(hand_type) {
+                    write!(f, "{} beat {}", winner.plural_name(), loser.plural_name())
+                } else if position == 0 {
+                    write!(f, "{} high beats {} high", winner.name(), loser.name())
+                } else {
+                    write!(f, "{} kicker beats {}", winner.name(), loser.name())
+                }
+            }
+            Reason::Tie { hand_type } => {
+                write!(f, "tie, both ")?;
+                write_shared(f, hand_type, &self.shared)
+            }
+        }
+    }
+}
+
+/// Writes the category and the ranks two hands share, such as
+/// `"two pair Jacks and Eights"` or `"flush Ace high with King"`.
+fn write_shared(f: &mut fmt::Formatter, hand_type: HandType, shared: &[Rank]) -> fmt::Result {
+    write!(f, "{}", hand_type.to_string().to_lowercase())?;
+    let groups = groups(hand_type).min(shared.len());
+    let (grouped, singles) = shared.split_at(groups);
+    for (i, rank) in grouped.iter().enumerate() {
+        let separator = if i == 0 { " " } else { " and " };
+        write!(f, "{}{}", separator, rank.plural_name())?;
+    }
+    let singles = match singles.split_first() {
+        Some((high, rest)) if groups == 0 => {
+            write!(f, " {} high", high.name())?;
+            rest
+        }
+        _ => singles,
+    };
+    for (i, rank) in singles.iter().enumerate() {
+        let separator = if i == 0 { " with " } else { ", " };
+        write!(f, "{}{}", separator, rank.name())?;
+    }
+    Ok(())
+}
+
+/// How many ranks at the start of a category's tie breaker name groups of
+/// cards, such as the two pairs of two pair, rather than single cards.
+fn groups(hand_type: HandType) -> usize {
+    match hand_type {
+        HandType::HighCard | HandType::Straight | HandType::Flush | HandType::StraightFlush => 0,
+        HandType::OnePair
+        | HandType::ThreeOfAKind
+        | HandType::FourOfAKind
+        | HandType::FiveOfAKind => 1,
+        HandType::TwoPair | HandType::FullHouse => 2,
+    }
+}
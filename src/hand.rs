@@ -0,0 +1,360 @@
+use std::cmp::Reverse;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::card::parse_card;
+use crate::{Card, CardSet, PokerError, Rank, RankingRules};
+
+/// The category of a poker hand, weakest first.
+#[derive(Ord, Eq, PartialEq, PartialOrd, Clone, Copy, Debug, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    /// Only possible with wild cards.
+    FiveOfAKind,
+}
+
+/// The value of a five-card poker hand: its category plus the ranks needed to
+/// break ties between hands of the same category.
+///
+/// Evaluated hands compare the way the hands themselves do, so they can be
+/// sorted, bucketed or compared directly. Two hands which split a pot are
+/// equal.
+///
+/// The tie breaker lists the ranks that decide between hands of the same
+/// category, most significant first:
+///
+/// - high card and flush: all five cards
+/// - one pair: the pair, then the three kickers
+/// - two pair: the high pair, the low pair, then the kicker
+/// - three of a kind: the triplet, then the two kickers
+/// - straight and straight flush: the highest card (five for a wheel)
+/// - full house: the triplet, then the pair
+/// - four of a kind: the quad, then the kicker
+/// - five of a kind: its rank
+///
+/// Hands evaluated under other [`RankingRules`] use the same layout, with
+/// the ranks ordered by that game's card values, but only compare correctly
+/// through [`RankingRules::compare`].
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub struct EvaluatedHand {
+    hand_type: HandType,
+    tie_breaker: Vec<Rank>,
+}
+
+impl EvaluatedHand {
+    pub fn hand_type(&self) -> HandType {
+        self.hand_type
+    }
+
+    pub fn tie_breaker(&self) -> &[Rank] {
+        &self.tie_breaker
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            HandType::HighCard => "High card",
+            HandType::OnePair => "One pair",
+            HandType::TwoPair => "Two pair",
+            HandType::ThreeOfAKind => "Three of a kind",
+            HandType::Straight => "Straight",
+            HandType::Flush => "Flush",
+            HandType::FullHouse => "Full house",
+            HandType::FourOfAKind => "Four of a kind",
+            HandType::StraightFlush => "Straight flush",
+            HandType::FiveOfAKind => "Five of a kind",
+        })
+    }
+}
+
+/// Describes the hand the way a dealer would announce it, such as
+/// `"Full house, Kings full of Fours"`, `"Straight, Five high"` or
+/// `"Two pair, Jacks and Eights with a Queen kicker"`.
+///
+/// The description names the ranks that make the hand, and the kicker when
+/// there is exactly one. An ace-high straight flush is a `"Royal flush"`.
+impl fmt::Display for EvaluatedHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranks = &self.tie_breaker;
+        match self.hand_type {
+            HandType::StraightFlush if ranks[0] == Rank::Ace => f.write_str("Royal flush"),
+            HandType::HighCard => write!(f, "{}, {}", self.hand_type, ranks[0].name()),
+            HandType::Straight | HandType::Flush | HandType::StraightFlush => {
+                write!(f, "{}, {} high", self.hand_type, ranks[0].name())
+            }
+            HandType::OnePair | HandType::ThreeOfAKind | HandType::FiveOfAKind => {
+                write!(f, "{}, {}", self.hand_type, ranks[0].plural_name())
+            }
+            HandType::TwoPair => write!(
+                f,
+                "{}, {} and {} with a{} {} kicker",
+                self.hand_type,
+                ranks[0].plural_name(),
+                ranks[1].plural_name(),
+                article_suffix(ranks[2]),
+                ranks[2].name()
+            ),
+            HandType::FullHouse => write!(
+                f,
+                "{}, {} full of {}",
+                self.hand_type,
+                ranks[0].plural_name(),
+                ranks[1].plural_name()
+            ),
+            HandType::FourOfAKind => write!(
+                f,
+                "{}, {} with a{} {} kicker",
+                self.hand_type,
+                ranks[0].plural_name(),
+                article_suffix(ranks[1]),
+                ranks[1].name()
+            ),
+        }
+    }
+}
+
+/// `"n"` if the rank's name takes "an" rather than "a".
+fn article_suffix(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Eight | Rank::Ace => "n",
+        _ => "",
+    }
+}
+
+type IsFlush = bool;
+/// How many cards there are of each rank, most significant first.
+type Groups = Vec<(u8, Rank)>;
+/// The top card of the straight, if the hand is one.
+type Straight = Option<Rank>;
+type HandProfile = (IsFlush, Groups, Straight);
+
+/// Evaluates a hand written as five whitespace separated cards, e.g.
+/// `"4S 5S 7H 8D JC"`.
+pub fn evaluate(hand: &str) -> Result<EvaluatedHand, PokerError> {
+    let cards = parse_hand(hand, 5..=5)?;
+    Ok(evaluate_five(&cards))
+}
+
+/// Evaluates a hand of exactly five distinct cards.
+///
+/// Errors use the position of the offending card in `cards` as their offset.
+pub fn evaluate_cards(cards: &[Card]) -> Result<EvaluatedHand, PokerError> {
+    check_distinct(cards)?;
+    check_card_count(cards.len(), 5..=5, || join(cards))?;
+    Ok(evaluate_five(cards))
+}
+
+/// Splits a string on whitespace, yielding each token with its byte offset.
+pub(crate) fn tokenize(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - s.as_ptr() as usize, token))
+}
+
+/// Parses a hand of distinct cards, checking it has a number of cards in
+/// `expected`.
+pub(crate) fn parse_hand(
+    hand_str: &str,
+    expected: RangeInclusive<usize>,
+) -> Result<Vec<Card>, PokerError> {
+    let mut cards: Vec<Card> = Vec::with_capacity(5);
+    let mut seen = CardSet::new();
+
+    for (offset, token) in tokenize(hand_str) {
+        let card = parse_card(token, offset)?;
+        if !seen.insert(card) {
+            return Err(PokerError::DuplicateCard {
+                offset,
+                token: token.to_string(),
+            });
+        }
+        cards.push(card);
+    }
+
+    check_card_count(cards.len(), expected, || hand_str.to_string())?;
+    Ok(cards)
+}
+
+/// Checks that a group of cards is the size the game calls for. `token`
+/// builds the text reported in the error.
+pub(crate) fn check_card_count(
+    count: usize,
+    expected: RangeInclusive<usize>,
+    token: impl FnOnce() -> String,
+) -> Result<(), PokerError> {
+    if expected.contains(&count) {
+        Ok(())
+    } else if count == 0 {
+        Err(PokerError::EmptyHand {
+            offset: 0,
+            token: token(),
+        })
+    } else {
+        Err(PokerError::WrongCardCount {
+            offset: 0,
+            token: token(),
+            count,
+        })
+    }
+}
+
+/// Checks that no card appears twice, reporting the position of the second
+/// occurrence.
+pub(crate) fn check_distinct(cards: &[Card]) -> Result<(), PokerError> {
+    let mut seen = CardSet::new();
+    for (i, card) in cards.iter().enumerate() {
+        if !seen.insert(*card) {
+            return Err(PokerError::DuplicateCard {
+                offset: i,
+                token: card.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Writes cards in the same notation hands are parsed from.
+pub(crate) fn join(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(Card::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Calls `f` with every combination of `k` cards drawn from `cards`, keeping
+/// their original order.
+pub(crate) fn for_each_combination(cards: &[Card], k: usize, mut f: impl FnMut(&[Card])) {
+    fn recurse(cards: &[Card], k: usize, chosen: &mut Vec<Card>, f: &mut impl FnMut(&[Card])) {
+        if chosen.len() == k {
+            f(chosen);
+            return;
+        }
+        let needed = k - chosen.len();
+        if cards.len() < needed {
+            return;
+        }
+        for i in 0..=cards.len() - needed {
+            chosen.push(cards[i]);
+            recurse(&cards[i + 1..], k, chosen, f);
+            chosen.pop();
+        }
+    }
+
+    recurse(cards, k, &mut Vec::with_capacity(k), &mut f);
+}
+
+pub(crate) fn evaluate_five(cards: &[Card]) -> EvaluatedHand {
+    evaluate_five_with(cards, RankingRules::High)
+}
+
+pub(crate) fn evaluate_five_with(cards: &[Card], rules: RankingRules) -> EvaluatedHand {
+    let profile: HandProfile = profile_hand(cards, rules);
+    let (tie_breaker, hand_type) = determine_hand_type(profile);
+    EvaluatedHand {
+        hand_type,
+        tie_breaker,
+    }
+}
+
+fn determine_hand_type(profile: HandProfile) -> (Vec<Rank>, HandType) {
+    let (is_flush, groups, straight) = profile;
+    let counts: Vec<u8> = groups.iter().map(|(count, _)| *count).collect();
+    let hand_type: HandType;
+
+    if counts == [5] {
+        // 5 of a kind, only made with wild cards
+        hand_type = HandType::FiveOfAKind;
+    } else if straight.is_some() && is_flush {
+        // straight flush
+        hand_type = HandType::StraightFlush;
+    } else if counts == [4, 1] {
+        // 4 of a kind
+        hand_type = HandType::FourOfAKind;
+    } else if counts == [3, 2] {
+        // full house
+        hand_type = HandType::FullHouse;
+    } else if is_flush {
+        // flush
+        hand_type = HandType::Flush;
+    } else if straight.is_some() {
+        // straight
+        hand_type = HandType::Straight;
+    } else if counts == [3, 1, 1] {
+        // three of a kind
+        hand_type = HandType::ThreeOfAKind;
+    } else if counts == [2, 2, 1] {
+        // two pair
+        hand_type = HandType::TwoPair;
+    } else if counts == [2, 1, 1, 1] {
+        // one pair
+        hand_type = HandType::OnePair;
+    } else {
+        // high card
+        hand_type = HandType::HighCard;
+    }
+
+    // the groups are already in tie breaking order, apart from straights
+    // which only need their top card
+    let tie_breaker = match straight {
+        Some(top) => vec![top],
+        _ => groups.iter().map(|(_, rank)| *rank).collect(),
+    };
+    (tie_breaker, hand_type)
+}
+
+/// Returns the top card of the straight formed by five distinct ranks, given
+/// highest first, or `None` if they do not form one.
+fn straight_top(ranks: &[Rank], rules: RankingRules) -> Option<Rank> {
+    let (highest, lowest) = (ranks[0], ranks[4]);
+    if highest.value() - lowest.value() == 4 {
+        return Some(highest);
+    }
+
+    // handle low ace: the other four cards must run down from the top of
+    // the wheel
+    let wheel_top = rules.wheel_top()?;
+    if highest == Rank::Ace && ranks[1] == wheel_top && wheel_top.value() - lowest.value() == 3 {
+        Some(wheel_top)
+    } else {
+        None
+    }
+}
+
+// characterise hand
+// - group cards of a kind, largest group first, then by rank
+// - identify straights and flushes
+fn profile_hand(cards: &[Card], rules: RankingRules) -> HandProfile {
+    let mut groups: Groups = Vec::with_capacity(5);
+    for card in cards {
+        match groups.iter_mut().find(|(_, rank)| *rank == card.rank()) {
+            Some((count, _)) => *count += 1,
+            None => groups.push((1, card.rank())),
+        }
+    }
+    groups.sort_by_key(|(count, rank)| Reverse((*count, rules.rank_value(*rank))));
+
+    let counts_straights_and_flushes = rules.counts_straights_and_flushes();
+
+    // flush
+    let is_flush =
+        counts_straights_and_flushes && cards.iter().all(|c| c.suit() == cards[0].suit());
+
+    // straights need five different ranks
+    let straight = if counts_straights_and_flushes && groups.len() == 5 {
+        let ranks: Vec<Rank> = groups.iter().map(|(_, rank)| *rank).collect();
+        straight_top(&ranks, rules)
+    } else {
+        None
+    };
+
+    (is_flush, groups, straight)
+}
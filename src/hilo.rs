@@ -0,0 +1,141 @@
+use crate::hand::{
+    check_card_count, check_distinct, evaluate_five, for_each_combination, join, parse_hand,
+};
+use crate::low::eight_or_better;
+use crate::{best_group, BestHand, Card, LowHand, PokerError};
+
+/// Both halves of a hand in a high-low split game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HiLoHand {
+    pub(crate) high: BestHand,
+    pub(crate) low: Option<BestHand<LowHand>>,
+}
+
+impl HiLoHand {
+    pub fn high(&self) -> &BestHand {
+        &self.high
+    }
+
+    /// The best eight-or-better low, if the hand makes one.
+    pub fn low(&self) -> Option<&BestHand<LowHand>> {
+        self.low.as_ref()
+    }
+}
+
+/// The winners of each half of a split pot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitPot<T> {
+    high: Vec<T>,
+    low: Vec<T>,
+}
+
+impl<T: PartialEq> SplitPot<T> {
+    /// Everyone sharing the high half.
+    pub fn high(&self) -> &[T] {
+        &self.high
+    }
+
+    /// Everyone sharing the low half. Empty when no hand qualifies for low,
+    /// in which case the high winners take the whole pot.
+    pub fn low(&self) -> &[T] {
+        &self.low
+    }
+
+    /// Whether any hand qualified for low, so that the pot is split.
+    pub fn has_low(&self) -> bool {
+        !self.low.is_empty()
+    }
+
+    /// The player who wins the whole pot outright, if there is one: the sole
+    /// high winner who is also the sole low winner, or the sole high winner
+    /// when nobody makes a low.
+    pub fn scooper(&self) -> Option<&T> {
+        match (self.high.as_slice(), self.low.as_slice()) {
+            ([high], []) => Some(high),
+            ([high], [low]) if high == low => Some(high),
+            _ => None,
+        }
+    }
+}
+
+/// Evaluates a stud-style hand of five to seven cards for a high-low split
+/// game, using any five cards for the high and any five for the low.
+///
+/// Errors use the position of the offending card in `cards` as their offset.
+pub fn evaluate_hi_lo(cards: &[Card]) -> Result<HiLoHand, PokerError> {
+    check_distinct(cards)?;
+    check_card_count(cards.len(), 5..=7, || join(cards))?;
+    Ok(hi_lo_of(cards))
+}
+
+/// Splits a pot between the best high hands and the best eight-or-better
+/// lows, as in Stud-8. Each hand is a string of five to seven cards.
+///
+/// As with [`winning_hands`](crate::winning_hands), the winners are the
+/// same references as were passed in.
+///
+/// # Panics
+///
+/// Panics if any of the hands is malformed. Use [`try_hi_lo_winners`] to get
+/// the error back instead.
+pub fn hi_lo_winners<'a>(hands: &[&'a str]) -> Option<SplitPot<&'a str>> {
+    match try_hi_lo_winners(hands) {
+        Ok(winners) => winners,
+        Err(e) => panic!("Malformed hand: {}", e),
+    }
+}
+
+/// Fallible version of [`hi_lo_winners`].
+pub fn try_hi_lo_winners<'a>(hands: &[&'a str]) -> Result<Option<SplitPot<&'a str>>, PokerError> {
+    let evaluated_hands = hands
+        .iter()
+        .map(|hand| Ok((hi_lo_of(&parse_hand(hand, 5..=7)?), *hand)))
+        .collect::<Result<Vec<(HiLoHand, &'a str)>, PokerError>>()?;
+    Ok(split(evaluated_hands))
+}
+
+/// Splits a pot between already evaluated hands, such as those from
+/// [`evaluate_omaha_hi_lo`](crate::evaluate_omaha_hi_lo), returning the
+/// positions of the winners in `hands`.
+pub fn split_hi_lo(hands: &[HiLoHand]) -> Option<SplitPot<usize>> {
+    split(hands.iter().cloned().zip(0..).collect())
+}
+
+fn split<T: Clone>(hands: Vec<(HiLoHand, T)>) -> Option<SplitPot<T>> {
+    let lows = hands
+        .iter()
+        .filter_map(|(hand, item)| Some((hand.low.clone()?, item.clone())))
+        .collect();
+    let high = best_group(hands, |a, b| a.high.hand().cmp(b.high.hand()));
+    // lower lows are better
+    let low = best_group(lows, |a, b| b.hand().cmp(a.hand()));
+
+    if high.is_empty() {
+        None
+    } else {
+        Some(SplitPot { high, low })
+    }
+}
+
+fn hi_lo_of(cards: &[Card]) -> HiLoHand {
+    let mut high: Option<BestHand> = None;
+    let mut low: Option<BestHand<LowHand>> = None;
+
+    for_each_combination(cards, 5, |five| {
+        let hand = evaluate_five(five);
+        if high.as_ref().is_none_or(|best| hand > *best.hand()) {
+            high = Some(BestHand::new(hand, five));
+        }
+
+        if let Some(hand) = eight_or_better(five) {
+            if low.as_ref().is_none_or(|best| hand < *best.hand()) {
+                low = Some(BestHand::new(hand, five));
+            }
+        }
+    });
+
+    HiLoHand {
+        high: high.expect("there are enough cards for a hand"),
+        low,
+    }
+}
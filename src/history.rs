@@ -0,0 +1,544 @@
+use std::str::FromStr;
+
+use crate::card::parse_card;
+use crate::hand::tokenize;
+use crate::{
+    evaluate_holdem, evaluate_omaha, settle, Card, Contribution, OddChip, PokerError, RankingRules,
+    Settlement, Street,
+};
+
+/// A hand history in the text format PokerStars writes, such as:
+///
+/// ```text
+/// PokerStars Hand #1001: Hold'em No Limit ($0.01/$0.02 USD) - 2020/01/01 12:00:00 ET
+/// Table 'Alpha' 6-max Seat #1 is the button
+/// Seat 1: Alice ($2 in chips)
+/// Seat 2: Bob ($2 in chips)
+/// Alice: posts small blind $0.01
+/// Bob: posts big blind $0.02
+/// *** HOLE CARDS ***
+/// Dealt to Alice [Ah Kd]
+/// Alice: raises $0.04 to $0.06
+/// Bob: folds
+/// Uncalled bet ($0.04) returned to Alice
+/// Alice collected $0.04 from pot
+/// ```
+///
+/// Amounts are whole numbers: cents where the history writes them with a
+/// currency sign, as cash games do, and chips otherwise. Players are
+/// numbered from 0 in the order their seats are listed. Lines that do not
+/// bear on the play, such as chat and the summary, are skipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HandHistory {
+    /// The hand number, such as `1001`.
+    pub id: String,
+    /// The game as the first line names it, such as `Hold'em No Limit`.
+    pub game: String,
+    pub table: String,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    /// The seat number of the button, as written.
+    pub button: usize,
+    pub seats: Vec<HistorySeat>,
+    pub actions: Vec<HistoryAction>,
+    /// The community cards, in the order they were dealt.
+    pub board: Vec<Card>,
+    /// Uncalled bets handed back, by player.
+    pub returned: Vec<(usize, u64)>,
+    /// Each amount a player collected from a pot, in the order written.
+    pub collected: Vec<(usize, u64)>,
+}
+
+/// A player seated at the start of a hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistorySeat {
+    /// The seat number, as written.
+    pub number: usize,
+    pub name: String,
+    pub chips: u64,
+    /// The player's hole cards, if they were dealt face up to the history's
+    /// owner or shown.
+    pub hole_cards: Option<Vec<Card>>,
+}
+
+/// One thing a player did during a hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryAction {
+    /// The street the action was taken on; showing and mucking after the
+    /// river are on [`Street::Showdown`].
+    pub street: Street,
+    pub player: usize,
+    pub action: RecordedAction,
+    /// Whether the action put the player all in.
+    pub all_in: bool,
+}
+
+/// An action as a hand history records it, with the amounts written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordedAction {
+    SmallBlind(u64),
+    BigBlind(u64),
+    /// Both blinds posted at once, as by a player coming back into the game.
+    /// The small blind is dead money; only the big blind counts towards the
+    /// player's bet.
+    SmallAndBigBlinds(u64),
+    Ante(u64),
+    Fold,
+    Check,
+    /// The chips added to call.
+    Call(u64),
+    Bet(u64),
+    /// A raise by `by` chips to a bet of `to` on the street.
+    Raise {
+        by: u64,
+        to: u64,
+    },
+    Show(Vec<Card>),
+    Muck,
+}
+
+impl HandHistory {
+    /// Parses every hand in a file of hand histories, each starting with a
+    /// `PokerStars` line. Error offsets are relative to the start of `text`.
+    pub fn parse_all(text: &str) -> Result<Vec<HandHistory>, PokerError> {
+        let mut starts: Vec<usize> = lines(text, 0)
+            .filter(|line| {
+                line.text
+                    .trim_start_matches('\u{feff}')
+                    .starts_with("PokerStars ")
+            })
+            .map(|line| line.offset)
+            .collect();
+        if starts.first() != Some(&0) {
+            starts.insert(0, 0);
+        }
+        starts.push(text.len());
+        starts
+            .windows(2)
+            .map(|pair| (pair[0], &text[pair[0]..pair[1]]))
+            .filter(|(_, hand)| !hand.trim().trim_start_matches('\u{feff}').is_empty())
+            .map(|(start, hand)| parse_history(hand, start))
+            .collect()
+    }
+
+    /// The player seated under `name`.
+    pub fn player(&self, name: &str) -> Option<usize> {
+        self.seats.iter().position(|seat| seat.name == name)
+    }
+
+    /// What each player put into the pot and, for those who showed down,
+    /// the best five cards they showed, ready for [`settle`]. Players who
+    /// folded or mucked count as folded.
+    ///
+    /// Shown hands are evaluated as Omaha hands if the game is Omaha, and
+    /// as Hold'em hands otherwise, which gives an error if a shown hand is
+    /// the wrong size. Hands shown before the flop are ignored.
+    pub fn contributions(&self) -> Result<Vec<Contribution>, PokerError> {
+        let mut players: Vec<Contribution> = self
+            .seats
+            .iter()
+            .map(|_| Contribution {
+                chips: 0,
+                folded: false,
+                hand: None,
+            })
+            .collect();
+        let mut bets = vec![0; self.seats.len()];
+        let mut street = Street::Preflop;
+        for action in &self.actions {
+            if action.street != street {
+                street = action.street;
+                bets.iter_mut().for_each(|bet| *bet = 0);
+            }
+            let player = &mut players[action.player];
+            let bet = &mut bets[action.player];
+            match &action.action {
+                RecordedAction::Ante(chips) => player.chips += chips,
+                RecordedAction::SmallBlind(chips)
+                | RecordedAction::BigBlind(chips)
+                | RecordedAction::Call(chips)
+                | RecordedAction::Bet(chips) => {
+                    player.chips += chips;
+                    *bet += chips;
+                }
+                RecordedAction::SmallAndBigBlinds(chips) => {
+                    player.chips += chips;
+                    *bet += (*chips).min(self.big_blind);
+                }
+                RecordedAction::Raise { to, .. } => {
+                    player.chips += to.saturating_sub(*bet);
+                    *bet = (*bet).max(*to);
+                }
+                RecordedAction::Fold | RecordedAction::Muck => player.folded = true,
+                RecordedAction::Show(cards) => player.hand = Some(cards.clone()),
+                RecordedAction::Check => {}
+            }
+        }
+        for (player, chips) in &self.returned {
+            players[*player].chips = players[*player].chips.saturating_sub(*chips);
+        }
+        // with no board there was no showdown, so hands shown do not count
+        if self.board.len() < 3 {
+            players.iter_mut().for_each(|player| player.hand = None);
+        }
+        for player in &mut players {
+            if let Some(hole) = &player.hand {
+                let best = if self.game.contains("Omaha") {
+                    evaluate_omaha(hole, &self.board)?
+                } else {
+                    evaluate_holdem(hole, &self.board)?
+                };
+                player.hand = Some(best.cards().to_vec());
+            }
+        }
+        Ok(players)
+    }
+
+    /// Shares out the pot again from the recorded play, with the crate's own
+    /// hand comparison deciding the showdown. Odd chips go left of the
+    /// button. Errors are those of [`contributions`](HandHistory::contributions)
+    /// and [`settle`].
+    ///
+    /// Hi/Lo games, whose pots are split between a high and a low hand,
+    /// cannot be settled this way, and give
+    /// [`InvalidHistory`](PokerError::InvalidHistory) with the game as its
+    /// token.
+    pub fn settle(&self) -> Result<Settlement, PokerError> {
+        if self.game.contains("Hi/Lo") {
+            return Err(PokerError::InvalidHistory {
+                offset: 0,
+                token: self.game.clone(),
+            });
+        }
+        let button = self
+            .seats
+            .iter()
+            .position(|seat| seat.number == self.button)
+            .unwrap_or(0);
+        settle(
+            &self.contributions()?,
+            RankingRules::High,
+            OddChip::LeftOfButton { button },
+        )
+    }
+
+    /// The players recorded as collecting from a pot, in seat order.
+    pub fn winners(&self) -> Vec<usize> {
+        let mut winners: Vec<usize> = self.collected.iter().map(|(player, _)| *player).collect();
+        winners.sort_unstable();
+        winners.dedup();
+        winners
+    }
+
+    /// Whether the players who collected are exactly those the crate's
+    /// evaluator says should have won something. Amounts are not compared,
+    /// since the recorded ones are net of rake. Errors are those of
+    /// [`settle`](HandHistory::settle).
+    pub fn winners_match(&self) -> Result<bool, PokerError> {
+        let settlement = self.settle()?;
+        let expected: Vec<usize> = (0..self.seats.len())
+            .filter(|player| settlement.payouts()[*player] > 0)
+            .collect();
+        Ok(expected == self.winners())
+    }
+}
+
+impl FromStr for HandHistory {
+    type Err = PokerError;
+
+    /// Parses a single hand history.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_history(s, 0)
+    }
+}
+
+/// One line of a hand history and its byte offset in the text being parsed.
+#[derive(Clone, Copy)]
+struct Line<'a> {
+    offset: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The offset of `part`, which must be a slice of this line.
+    fn at(&self, part: &str) -> usize {
+        self.offset + (part.as_ptr() as usize - self.text.as_ptr() as usize)
+    }
+
+    fn invalid(&self) -> PokerError {
+        PokerError::InvalidHistory {
+            offset: self.offset,
+            token: self.text.to_string(),
+        }
+    }
+
+    /// Reads an amount that is a slice of this line.
+    fn amount(&self, token: &str) -> Result<u64, PokerError> {
+        parse_amount(token).ok_or_else(|| PokerError::InvalidHistory {
+            offset: self.at(token),
+            token: token.to_string(),
+        })
+    }
+
+    /// Reads the cards in the first bracketed group in the line, or the last
+    /// one if `last` is set.
+    fn cards(&self, last: bool) -> Result<Vec<Card>, PokerError> {
+        let open = if last {
+            self.text.rfind('[')
+        } else {
+            self.text.find('[')
+        };
+        let group = open
+            .and_then(|open| {
+                let inner = &self.text[open + 1..];
+                inner.find(']').map(|close| &inner[..close])
+            })
+            .ok_or_else(|| self.invalid())?;
+        tokenize(group)
+            .map(|(at, token)| parse_history_card(token, self.at(group) + at))
+            .collect()
+    }
+}
+
+fn lines(text: &str, base: usize) -> impl Iterator<Item = Line<'_>> {
+    text.split('\n').map(move |line| Line {
+        offset: base + (line.as_ptr() as usize - text.as_ptr() as usize),
+        text: line.trim_end_matches('\r'),
+    })
+}
+
+/// Parses one hand history found at byte `base` of a larger text.
+fn parse_history(text: &str, base: usize) -> Result<HandHistory, PokerError> {
+    let mut lines = lines(text, base).filter(|line| !line.text.trim().is_empty());
+    let header = lines.next().ok_or(PokerError::InvalidHistory {
+        offset: base,
+        token: String::new(),
+    })?;
+    let mut history = parse_header(header)?;
+
+    let mut street = Street::Preflop;
+    let mut dealt = false;
+    for line in lines {
+        if let Some(marker) = line.text.strip_prefix("*** ") {
+            let next = if marker.starts_with("HOLE CARDS") {
+                dealt = true;
+                Street::Preflop
+            } else if marker.starts_with("FLOP") {
+                Street::Flop
+            } else if marker.starts_with("TURN") {
+                Street::Turn
+            } else if marker.starts_with("RIVER") {
+                Street::River
+            } else if marker.starts_with("SHOW DOWN") {
+                Street::Showdown
+            } else if marker.starts_with("SUMMARY") {
+                break;
+            } else {
+                continue;
+            };
+            if next != street && next != Street::Showdown {
+                history.board.extend(line.cards(true)?);
+            }
+            street = next;
+            continue;
+        }
+
+        if let Some(rest) = line.text.strip_prefix("Table '") {
+            let (table, rest) = rest.rsplit_once('\'').ok_or_else(|| line.invalid())?;
+            let button = rest
+                .split_once("Seat #")
+                .and_then(|(_, seat)| seat.split(' ').next())
+                .and_then(|seat| seat.parse().ok())
+                .ok_or_else(|| line.invalid())?;
+            history.table = table.to_string();
+            history.button = button;
+        } else if line.text.starts_with("Seat ") && !dealt {
+            history.seats.push(parse_seat(line)?);
+        } else if let Some(rest) = line.text.strip_prefix("Dealt to ") {
+            let name = rest.rsplit_once(" [").ok_or_else(|| line.invalid())?.0;
+            let player = history.player(name).ok_or_else(|| line.invalid())?;
+            history.seats[player].hole_cards = Some(line.cards(true)?);
+        } else if let Some(rest) = line.text.strip_prefix("Uncalled bet (") {
+            let (amount, name) = rest
+                .split_once(") returned to ")
+                .ok_or_else(|| line.invalid())?;
+            let player = history.player(name).ok_or_else(|| line.invalid())?;
+            history.returned.push((player, line.amount(amount)?));
+        } else if let Some((name, rest)) = line.text.split_once(" collected ") {
+            let Some(player) = history.player(name) else {
+                continue;
+            };
+            let amount = rest.split(' ').next().unwrap_or(rest);
+            history.collected.push((player, line.amount(amount)?));
+        } else if let Some((player, rest)) = split_player(&history, line.text) {
+            if let Some((action, all_in)) = parse_action(line, rest)? {
+                if let RecordedAction::Show(cards) = &action {
+                    history.seats[player].hole_cards = Some(cards.clone());
+                }
+                history.actions.push(HistoryAction {
+                    street,
+                    player,
+                    action,
+                    all_in,
+                });
+            }
+        }
+    }
+
+    if history.seats.is_empty() {
+        return Err(header.invalid());
+    }
+    Ok(history)
+}
+
+/// Parses the first line of a history, such as
+/// `PokerStars Hand #1001: Hold'em No Limit ($0.01/$0.02 USD) - 2020/01/01`.
+fn parse_header(line: Line) -> Result<HandHistory, PokerError> {
+    let text = line.text.trim_start_matches('\u{feff}');
+    let (id, body) = text
+        .strip_prefix("PokerStars ")
+        .and_then(|rest| rest.split_once('#'))
+        .and_then(|(_, rest)| rest.split_once(": "))
+        .ok_or_else(|| line.invalid())?;
+    let game_end = [body.find(" ("), body.find(" - ")]
+        .iter()
+        .flatten()
+        .min()
+        .copied()
+        .unwrap_or(body.len());
+
+    // the blinds are the first bracketed pair of amounts
+    let (small_blind, big_blind) = body
+        .split('(')
+        .skip(1)
+        .find_map(|group| {
+            let stakes = group.split([')', ' ']).next()?;
+            let (small, big) = stakes.split_once('/')?;
+            Some((parse_amount(small)?, parse_amount(big)?))
+        })
+        .ok_or_else(|| line.invalid())?;
+
+    Ok(HandHistory {
+        id: id.to_string(),
+        game: body[..game_end].to_string(),
+        table: String::new(),
+        small_blind,
+        big_blind,
+        button: 0,
+        seats: Vec::new(),
+        actions: Vec::new(),
+        board: Vec::new(),
+        returned: Vec::new(),
+        collected: Vec::new(),
+    })
+}
+
+/// Parses a seat line such as `Seat 1: Alice ($2.00 in chips)`.
+fn parse_seat(line: Line) -> Result<HistorySeat, PokerError> {
+    let (number, rest) = line.text["Seat ".len()..]
+        .split_once(": ")
+        .ok_or_else(|| line.invalid())?;
+    let number = number.parse().map_err(|_| line.invalid())?;
+    let chips_end = rest.find(" in chips").ok_or_else(|| line.invalid())?;
+    let open = rest[..chips_end]
+        .rfind(" (")
+        .ok_or_else(|| line.invalid())?;
+    Ok(HistorySeat {
+        number,
+        name: rest[..open].to_string(),
+        chips: line.amount(&rest[open + 2..chips_end])?,
+        hole_cards: None,
+    })
+}
+
+/// Splits a line such as `Alice: folds` into the player and what follows
+/// the colon. Names may themselves contain `": "`, so the first split that
+/// names a seated player is taken.
+fn split_player<'a>(history: &HandHistory, text: &'a str) -> Option<(usize, &'a str)> {
+    text.match_indices(": ")
+        .find_map(|(i, _)| Some((history.player(&text[..i])?, &text[i + 2..])))
+}
+
+/// Parses what a player did, returning `None` for lines that are not
+/// actions, such as `Alice: sits out`. Posts of any kind not understood are
+/// errors, since the chips would go missing from the pot.
+fn parse_action(line: Line, rest: &str) -> Result<Option<(RecordedAction, bool)>, PokerError> {
+    let (rest, all_in) = match rest.strip_suffix(" and is all-in") {
+        Some(rest) => (rest, true),
+        None => (rest, false),
+    };
+    let action = if rest == "checks" {
+        RecordedAction::Check
+    } else if rest.starts_with("folds") {
+        RecordedAction::Fold
+    } else if rest.starts_with("mucks") {
+        RecordedAction::Muck
+    } else if rest.starts_with("shows") {
+        RecordedAction::Show(line.cards(false)?)
+    } else if let Some(amount) = rest.strip_prefix("calls ") {
+        RecordedAction::Call(line.amount(amount)?)
+    } else if let Some(amount) = rest.strip_prefix("bets ") {
+        RecordedAction::Bet(line.amount(amount)?)
+    } else if let Some(amounts) = rest.strip_prefix("raises ") {
+        let (by, to) = amounts.split_once(" to ").ok_or_else(|| line.invalid())?;
+        RecordedAction::Raise {
+            by: line.amount(by)?,
+            to: line.amount(to)?,
+        }
+    } else if let Some(amount) = rest.strip_prefix("posts small blind ") {
+        RecordedAction::SmallBlind(line.amount(amount)?)
+    } else if let Some(amount) = rest.strip_prefix("posts big blind ") {
+        RecordedAction::BigBlind(line.amount(amount)?)
+    } else if let Some(amount) = rest.strip_prefix("posts small & big blinds ") {
+        RecordedAction::SmallAndBigBlinds(line.amount(amount)?)
+    } else if let Some(amount) = rest.strip_prefix("posts the ante ") {
+        RecordedAction::Ante(line.amount(amount)?)
+    } else if rest.starts_with("posts ") {
+        // chips put in that the pot could not account for
+        return Err(line.invalid());
+    } else {
+        return Ok(None);
+    };
+    Ok(Some((action, all_in)))
+}
+
+/// Reads an amount such as `$1.25`, in cents, or `1,500`, in chips.
+fn parse_amount(token: &str) -> Option<u64> {
+    let (money, digits) = match token.strip_prefix(['$', '€', '£']) {
+        Some(digits) => (true, digits),
+        None => (false, token),
+    };
+    let digits = digits.replace(',', "");
+    if !money {
+        return digits.parse().ok();
+    }
+    let (whole, cents) = digits.split_once('.').unwrap_or((&digits, ""));
+    if cents.len() > 2 || !cents.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let cents: u64 = format!("{:0<2}", cents).parse().ok()?;
+    whole
+        .parse::<u64>()
+        .ok()?
+        .checked_mul(100)?
+        .checked_add(cents)
+}
+
+/// Reads a card as hand histories write it, such as `Ah` or `Td`, with
+/// errors reporting the token as written.
+pub(crate) fn parse_history_card(token: &str, offset: usize) -> Result<Card, PokerError> {
+    let mut card = token.to_ascii_uppercase();
+    if card.starts_with('T') {
+        card.replace_range(..1, "10");
+    }
+    parse_card(&card, offset).map_err(|error| match error {
+        PokerError::InvalidRank { .. } => PokerError::InvalidRank {
+            offset,
+            token: token.to_string(),
+        },
+        _ => PokerError::InvalidSuit {
+            offset,
+            token: token.to_string(),
+        },
+    })
+}
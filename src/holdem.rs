@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+use crate::hand::{
+    check_card_count, check_distinct, evaluate_five_with, for_each_combination, join,
+};
+use crate::{Card, EvaluatedHand, PokerError, RankingRules};
+
+/// The strongest five-card hand that can be made from a larger set of cards,
+/// together with the five cards that make it.
+///
+/// The hand is usually an [`EvaluatedHand`], but low hands use the same type
+/// with a [`LowHand`](crate::LowHand).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BestHand<H = EvaluatedHand> {
+    hand: H,
+    cards: [Card; 5],
+}
+
+impl<H> BestHand<H> {
+    /// `cards` must hold exactly five cards.
+    pub(crate) fn new(hand: H, cards: &[Card]) -> BestHand<H> {
+        let mut five = [cards[0]; 5];
+        five.copy_from_slice(cards);
+        BestHand { hand, cards: five }
+    }
+
+    pub fn hand(&self) -> &H {
+        &self.hand
+    }
+
+    /// The five cards making the hand, in the order they were given.
+    pub fn cards(&self) -> &[Card; 5] {
+        &self.cards
+    }
+}
+
+/// Picks the best five-card hand from five to seven distinct cards.
+///
+/// When several combinations make equally strong hands the first one found
+/// is returned. Errors use the position of the offending card in `cards` as
+/// their offset.
+pub fn best_hand(cards: &[Card]) -> Result<BestHand, PokerError> {
+    check_distinct(cards)?;
+    check_card_count(cards.len(), 5..=7, || join(cards))?;
+    Ok(best_of(cards, RankingRules::High))
+}
+
+/// Evaluates a Texas Hold'em hand: two hole cards plus a board of three to
+/// five cards, using any five of them.
+///
+/// Errors use the position of the offending card in the hole cards followed
+/// by the board as their offset.
+pub fn evaluate_holdem(hole: &[Card], board: &[Card]) -> Result<BestHand, PokerError> {
+    let cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+    check_distinct(&cards)?;
+    check_card_count(hole.len(), 2..=2, || join(hole))?;
+    check_card_count(board.len(), 3..=5, || join(board))?;
+    Ok(best_of(&cards, RankingRules::High))
+}
+
+/// Returns the best hand made of five of `cards` under `rules`. The caller
+/// must ensure there are at least five cards.
+pub(crate) fn best_of(cards: &[Card], rules: RankingRules) -> BestHand {
+    let mut best: Option<BestHand> = None;
+    for_each_combination(cards, 5, |combination| {
+        let hand = evaluate_five_with(combination, rules);
+        if best
+            .as_ref()
+            .is_none_or(|b| rules.compare(&hand, &b.hand) == Ordering::Greater)
+        {
+            best = Some(BestHand::new(hand, combination));
+        }
+    });
+    best.expect("there are enough cards for a hand")
+}
@@ -1,262 +1,202 @@
-use std::collections::{HashMap, HashSet};
-
-const ACE_VALUE: u8 = 14;
-const KING_VALUE: u8 = 13;
-const QUEEN_VALUE: u8 = 12;
-const JACK_VALUE: u8 = 11;
-
-#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
-enum Suit {
-    Spade,
-    Club,
-    Heart,
-    Diamond,
-}
-
-#[derive(Debug)]
-struct Card {
-    value: u8,
-    suit: Suit,
-}
-
-#[derive(Ord, Eq, PartialEq, PartialOrd, Clone, Copy, Debug)]
-enum HandType {
-    HighCard,
-    OnePair,
-    TwoPair,
-    ThreeOfAKind,
-    Straight,
-    Flush,
-    FullHouse,
-    FourOfAKind,
-    StraightFlush,
-}
-
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
-struct Hand<'a> {
-    hand_type: HandType,
-    tie_breaker: Vec<u8>,
-    input_string: &'a str,
-}
-
-type Suits = HashSet<Suit>;
-type OfAKinds = HashMap<u8, u8>;
-type IsStraight = bool;
-type HandProfile = (Suits, OfAKinds, IsStraight);
+use std::cmp::Ordering;
+
+mod betting;
+mod card;
+mod card_set;
+mod deck;
+mod equity;
+mod error;
+mod explain;
+mod hand;
+mod hilo;
+mod history;
+mod holdem;
+mod lookup;
+mod low;
+mod omaha;
+mod phh;
+mod range;
+mod rng;
+mod rules;
+mod settlement;
+mod table;
+mod wild;
+
+pub use betting::{Action, ActionError, Betting, Blinds, LegalActions, Street};
+pub use card::{Card, PlayingCard, Rank, Suit};
+pub use card_set::CardSet;
+pub use deck::Deck;
+pub use equity::{
+    exhaustive_equity, exhaustive_omaha_equity, monte_carlo_equity, range_equity, Budget, Equity,
+};
+pub use error::PokerError;
+pub use explain::{explain, Explanation, Reason};
+pub use hand::{evaluate, evaluate_cards, EvaluatedHand, HandType};
+pub use hilo::{evaluate_hi_lo, hi_lo_winners, split_hi_lo, try_hi_lo_winners, HiLoHand, SplitPot};
+pub use history::{HandHistory, HistoryAction, HistorySeat, RecordedAction};
+pub use holdem::{best_hand, evaluate_holdem, BestHand};
+pub use lookup::{hand_rank, HAND_RANKS};
+pub use low::LowHand;
+pub use omaha::{evaluate_omaha, evaluate_omaha_hi_lo};
+pub use phh::{PhhAction, PhhHand};
+pub use range::Range;
+pub use rules::RankingRules;
+pub use settlement::{settle, Contribution, OddChip, Pot, Settlement};
+pub use table::{Table, TableHand};
+pub use wild::{try_winning_hands_wild, winning_hands_wild, WildCards};
 
 /// Given a list of poker hands, return a list of those hands which win.
 ///
 /// Note the type signature: this function should return _the same_ reference to
 /// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
+///
+/// # Panics
+///
+/// Panics if any of the hands is malformed. Use [`try_winning_hands`] to get
+/// the error back instead.
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Option<Vec<&'a str>> {
-    let mut parsed_hands: Vec<Hand> = hands.iter().map(|hand| parse_hand(hand)).collect();
-    parsed_hands.sort();
-
-    let winner = match parsed_hands.pop() {
-        None => return None,
-        Some(x) => x,
-    };
-
-    parsed_hands.reverse();
-
-    let mut winning_strings: Vec<&'a str> = parsed_hands
-        .drain(..)
-        .take_while(|h| winner.hand_type == h.hand_type && winner.tie_breaker == h.tie_breaker)
-        .map(|h| h.input_string)
-        .collect();
-
-    winning_strings.push(winner.input_string);
-
-    return Some(winning_strings);
+    match try_winning_hands(hands) {
+        Ok(winners) => winners,
+        Err(e) => panic!("Malformed hand: {}", e),
+    }
 }
 
-fn parse_hand<'a>(hand_str: &'a str) -> Hand {
-    // parse cards
-    let mut cards: Vec<_> = hand_str
-        .clone()
-        .split_whitespace()
-        .map(|card| parse_card(card))
-        .collect();
-
-    let profile: HandProfile = profile_hand(&mut cards);
-
-    let (tie_breaker, hand_type) = determine_hand_type(profile, cards);
-
-    return Hand {
-        tie_breaker: tie_breaker,
-        input_string: hand_str,
-        hand_type: hand_type,
-    };
+/// Fallible version of [`winning_hands`].
+///
+/// Returns the error for the first malformed hand. Offsets in the error are
+/// relative to the start of that hand's string.
+pub fn try_winning_hands<'a>(hands: &[&'a str]) -> Result<Option<Vec<&'a str>>, PokerError> {
+    try_winning_hands_with(hands, RankingRules::High)
 }
 
-fn determine_hand_type(profile: HandProfile, cards: Vec<Card>) -> (Vec<u8>, HandType) {
-    let (suits, of_a_kinds, is_straight) = profile;
-    let max_of_a_kind_count = of_a_kinds.values().max().unwrap_or(&0);
-
-    let mut tie_breaker = Vec::new();
-    let card_values: Vec<u8> = cards.iter().rev().map(|c| c.value).collect();
-    let hand_type: HandType;
-
-    if is_straight && suits.len() == 1 {
-        // straight flush
-        hand_type = HandType::StraightFlush;
-        straight_tie_breaker(&cards, &mut tie_breaker);
-    } else if *max_of_a_kind_count == 4 {
-        // 4 of a kind
-        hand_type = HandType::FourOfAKind;
-        of_a_kind_tie_breaker(&of_a_kinds, &mut tie_breaker, &card_values);
-    } else if *max_of_a_kind_count == 3 && of_a_kinds.len() == 2 {
-        // full house
-        hand_type = HandType::FullHouse;
-        full_house_tie_breaker(&of_a_kinds, &mut tie_breaker);
-    } else if suits.len() == 1 {
-        // flush
-        hand_type = HandType::Flush;
-        tie_breaker.extend(card_values.iter());
-    } else if is_straight {
-        // straight
-        hand_type = HandType::Straight;
-        straight_tie_breaker(&cards, &mut tie_breaker);
-    } else if *max_of_a_kind_count == 3 {
-        // three of a kind
-        hand_type = HandType::ThreeOfAKind;
-        of_a_kind_tie_breaker(&of_a_kinds, &mut tie_breaker, &card_values);
-    } else if of_a_kinds.len() == 2 {
-        // two pair
-        hand_type = HandType::TwoPair;
-        two_pair_tie_breaker(&of_a_kinds, &mut tie_breaker, &card_values);
-    } else if of_a_kinds.len() == 1 {
-        // one pair
-        hand_type = HandType::OnePair;
-        of_a_kind_tie_breaker(&of_a_kinds, &mut tie_breaker, &card_values);
-    } else {
-        // high card
-        hand_type = HandType::HighCard;
-        tie_breaker.extend(card_values.iter());
+/// Like [`winning_hands`], but picks the winners under the given ranking
+/// rules, e.g. for lowball games.
+///
+/// # Panics
+///
+/// Panics if any of the hands is malformed. Use [`try_winning_hands_with`]
+/// to get the error back instead.
+pub fn winning_hands_with<'a>(hands: &[&'a str], rules: RankingRules) -> Option<Vec<&'a str>> {
+    match try_winning_hands_with(hands, rules) {
+        Ok(winners) => winners,
+        Err(e) => panic!("Malformed hand: {}", e),
     }
-    (tie_breaker, hand_type)
 }
 
-fn two_pair_tie_breaker(
-    of_a_kinds: &HashMap<u8, u8>,
-    tie_breaker: &mut Vec<u8>,
-    card_values: &Vec<u8>,
-) {
-    let mut keys: Vec<u8> = of_a_kinds.keys().cloned().collect();
-    keys.sort();
-    keys.reverse();
-
-    for pair in keys {
-        tie_breaker.push(pair);
+/// Fallible version of [`winning_hands_with`].
+pub fn try_winning_hands_with<'a>(
+    hands: &[&'a str],
+    rules: RankingRules,
+) -> Result<Option<Vec<&'a str>>, PokerError> {
+    let evaluated_hands = hands
+        .iter()
+        .map(|hand| Ok((rules.evaluate(hand)?, *hand)))
+        .collect::<Result<Vec<(EvaluatedHand, &'a str)>, PokerError>>()?;
+    let winning_strings = best_group(evaluated_hands, |a, b| rules.compare(a, b));
+
+    if winning_strings.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(winning_strings))
     }
-
-    tie_breaker.extend(card_values.iter());
 }
 
-fn full_house_tie_breaker(of_a_kinds: &HashMap<u8, u8>, tie_breaker: &mut Vec<u8>) {
-    let mut pair_value = 0;
-    let mut triple_value = 0;
-
-    for (value, count) in of_a_kinds {
-        if *count == 3 {
-            triple_value = *value;
-        }
-        if *count == 2 {
-            pair_value = *value;
-        }
+/// Given a list of poker hands, returns every hand grouped into finishing
+/// places: the winners first, then the hands which tie for second, and so
+/// on. Hands which tie share a place, and within a place hands keep their
+/// input order.
+///
+/// Like [`winning_hands`], this returns _the same_ references as were passed
+/// in.
+///
+/// # Panics
+///
+/// Panics if any of the hands is malformed. Use [`try_rank_hands`] to get
+/// the error back instead.
+pub fn rank_hands<'a>(hands: &[&'a str]) -> Vec<Vec<&'a str>> {
+    match try_rank_hands(hands) {
+        Ok(places) => places,
+        Err(e) => panic!("Malformed hand: {}", e),
     }
-    tie_breaker.push(triple_value);
-    tie_breaker.push(pair_value);
 }
 
-fn of_a_kind_tie_breaker(
-    of_a_kinds: &HashMap<u8, u8>,
-    tie_breaker: &mut Vec<u8>,
-    card_values: &Vec<u8>,
-) {
-    let value = of_a_kinds.keys().next().unwrap();
-    tie_breaker.push(*value);
-    tie_breaker.extend(card_values.iter());
+/// Fallible version of [`rank_hands`].
+///
+/// Returns the error for the first malformed hand. Offsets in the error are
+/// relative to the start of that hand's string.
+pub fn try_rank_hands<'a>(hands: &[&'a str]) -> Result<Vec<Vec<&'a str>>, PokerError> {
+    try_rank_hands_with(hands, RankingRules::High)
 }
 
-fn straight_tie_breaker(cards: &Vec<Card>, tie_breaker: &mut Vec<u8>) {
-    let mut highest_card = &cards.last().unwrap().value;
-
-    // handle low ace
-    if *highest_card == ACE_VALUE {
-        // FIX ME assumes hand has 5 cards
-        let second_card = &cards[3].value;
-        if *second_card == 5 {
-            highest_card = second_card;
-        }
+/// Like [`rank_hands`], but orders the hands under the given ranking rules.
+///
+/// # Panics
+///
+/// Panics if any of the hands is malformed. Use [`try_rank_hands_with`] to
+/// get the error back instead.
+pub fn rank_hands_with<'a>(hands: &[&'a str], rules: RankingRules) -> Vec<Vec<&'a str>> {
+    match try_rank_hands_with(hands, rules) {
+        Ok(places) => places,
+        Err(e) => panic!("Malformed hand: {}", e),
     }
-
-    tie_breaker.push(*highest_card);
 }
 
-// characterise hand
-// - count cards of a kind
-// - identify straights and flushes
-fn profile_hand(cards: &mut Vec<Card>) -> HandProfile {
-    cards.sort_by_key(|c| c.value);
-    let mut prev_value: u8 = 0;
-
-    let mut suits: HashSet<Suit> = HashSet::new();
-    let mut of_a_kinds: HashMap<u8, u8> = HashMap::new();
-    let mut is_straight = true;
-
-    for card in cards.iter() {
-        // flush
-        suits.insert(card.suit);
-
-        // pairs/of a kind
-        if prev_value == card.value {
-            of_a_kinds
-                .entry(card.value)
-                .and_modify(|v| *v += 1)
-                .or_insert(2);
-        }
+/// Fallible version of [`rank_hands_with`].
+pub fn try_rank_hands_with<'a>(
+    hands: &[&'a str],
+    rules: RankingRules,
+) -> Result<Vec<Vec<&'a str>>, PokerError> {
+    let evaluated_hands = hands
+        .iter()
+        .map(|hand| Ok((rules.evaluate(hand)?, *hand)))
+        .collect::<Result<Vec<(EvaluatedHand, &'a str)>, PokerError>>()?;
+    Ok(ranked_groups(evaluated_hands, |a, b| rules.compare(a, b)))
+}
 
-        // straights
-        // - check consecutive values
-        if prev_value + 1 != card.value && prev_value != 0 {
-            // - check for low ace
-            if prev_value != 5 && card.value != ACE_VALUE {
-                is_straight = false;
-            }
+/// Groups the items into tiers of tying hands, best first. Within a tier
+/// items keep their input order. `compare` returns `Greater` when the first
+/// hand wins.
+pub(crate) fn ranked_groups<H, T>(
+    mut hands: Vec<(H, T)>,
+    compare: impl Fn(&H, &H) -> Ordering,
+) -> Vec<Vec<T>> {
+    // a stable sort, best first, keeps tying items in input order
+    hands.sort_by(|(a, _), (b, _)| compare(b, a));
+
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    let mut previous: Option<H> = None;
+    for (hand, item) in hands {
+        match (&previous, groups.last_mut()) {
+            (Some(p), Some(group)) if compare(&hand, p) == Ordering::Equal => group.push(item),
+            _ => groups.push(vec![item]),
         }
-
-        prev_value = card.value;
+        previous = Some(hand);
     }
-    (suits, of_a_kinds, is_straight)
+    groups
 }
 
-fn parse_card(card_str: &str) -> Card {
-    let mut card_chars = card_str.chars();
-    let suit_char = card_chars.next_back();
+/// Returns the items whose hands tie for best under `compare`, or nothing if
+/// there are no items. `compare` returns `Greater` when the first hand wins.
+pub(crate) fn best_group<H, T>(
+    mut hands: Vec<(H, T)>,
+    compare: impl Fn(&H, &H) -> Ordering,
+) -> Vec<T> {
+    hands.sort_by(|(a, _), (b, _)| compare(a, b));
 
-    let suit = match suit_char {
-        Some('C') => Suit::Club,
-        Some('S') => Suit::Spade,
-        Some('H') => Suit::Heart,
-        Some('D') => Suit::Diamond,
-        None => panic!("Malformed card string: {:?}", card_str),
-        Some(_) => panic!("Malformed card string: {:?}", card_str),
+    let (winner, winner_item) = match hands.pop() {
+        None => return Vec::new(),
+        Some(x) => x,
     };
 
-    let value = match card_chars.as_str().parse::<u8>() {
-        Ok(v) => v,
-        Err(_) => match card_chars.next() {
-            Some('A') => ACE_VALUE,
-            Some('K') => KING_VALUE,
-            Some('Q') => QUEEN_VALUE,
-            Some('J') => JACK_VALUE,
-            _ => panic!("Malformed card string: {:?}", card_str),
-        },
-    };
+    hands.reverse();
 
-    return Card {
-        suit: suit,
-        value: value,
-    };
+    let mut winning_items: Vec<T> = hands
+        .drain(..)
+        .take_while(|(h, _)| compare(h, &winner) == Ordering::Equal)
+        .map(|(_, item)| item)
+        .collect();
+
+    winning_items.push(winner_item);
+
+    winning_items
 }
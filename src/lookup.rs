@@ -0,0 +1,210 @@
+use std::sync::OnceLock;
+
+use crate::hand::evaluate_five;
+use crate::{Card, EvaluatedHand, Rank, Suit};
+
+/// The number of distinct five-card hand values, and so the highest rank
+/// [`hand_rank`] returns.
+pub const HAND_RANKS: u16 = 7462;
+
+struct Tables {
+    /// The best flush or straight flush that can be made from five to seven
+    /// cards of one suit, indexed by the bitmask of their ranks.
+    flushes: Vec<u16>,
+    /// The best hand, ignoring flushes, that can be made from five, six or
+    /// seven cards, indexed by [`rank_counts_index`] of their rank counts.
+    /// The entry for `n` cards is `unsuited[n - 5]`.
+    unsuited: [Vec<u16>; 3],
+    /// `ways[len][sum]` is the number of ways `len` ranks can hold `sum`
+    /// cards with at most four of each, used to index `unsuited`.
+    ways: [[u32; 8]; 14],
+}
+
+/// Ranks a hand of five, six or seven distinct cards by its best five-card
+/// hand under standard high-hand rules, from 1 for the worst high card hand
+/// (`7-5-4-3-2`) up to [`HAND_RANKS`] for a royal flush.
+///
+/// Hands compare exactly as their [`EvaluatedHand`]s do, so two hands tie if
+/// and only if they get the same rank. The lookup tables are built on first
+/// use; after that ranking a hand is a couple of table lookups and does no
+/// allocation.
+///
+/// # Panics
+///
+/// Panics if there are fewer than five or more than seven cards. Duplicate
+/// cards are not checked for.
+pub fn hand_rank(cards: &[Card]) -> u16 {
+    assert!(
+        (5..=7).contains(&cards.len()),
+        "cannot rank a hand of {} cards",
+        cards.len()
+    );
+    let mut suit_masks = [0usize; 4];
+    let mut counts = [0u8; 13];
+    for card in cards {
+        let rank = usize::from(card.rank().value() - 2);
+        suit_masks[card.suit() as usize] |= 1 << rank;
+        counts[rank] += 1;
+    }
+    rank_suit_masks(cards.len(), &suit_masks, &counts)
+}
+
+/// Ranks `len` cards given the ranks held in each suit, as bitmasks, and the
+/// number of cards of each rank.
+pub(crate) fn rank_suit_masks(len: usize, suit_masks: &[usize; 4], counts: &[u8; 13]) -> u16 {
+    let tables = tables();
+    // with seven cards or fewer, a hand holding a flush cannot also hold a
+    // full house or four of a kind, so the flush is its best hand
+    if let Some(mask) = suit_masks.iter().find(|m| m.count_ones() >= 5) {
+        return tables.flushes[*mask];
+    }
+    tables.unsuited[len - 5][rank_counts_index(&tables.ways, counts)]
+}
+
+/// Position of `counts` among every way of holding the same number of cards
+/// with at most four of each rank, in lexicographic order.
+fn rank_counts_index(ways: &[[u32; 8]; 14], counts: &[u8; 13]) -> usize {
+    let mut remaining = counts.iter().map(|c| usize::from(*c)).sum::<usize>();
+    let mut index = 0;
+    for (i, count) in counts.iter().enumerate() {
+        // skip every arrangement with fewer cards of this rank
+        for smaller in 0..usize::from(*count) {
+            index += ways[12 - i][remaining - smaller];
+        }
+        remaining -= usize::from(*count);
+    }
+    index as usize
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(build_tables)
+}
+
+/// Evaluates one hand of every distinct five-card value and numbers them in
+/// order, then derives the six and seven card entries from those.
+fn build_tables() -> Tables {
+    let mut ways = [[0u32; 8]; 14];
+    ways[0][0] = 1;
+    for len in 1..14 {
+        for sum in 0..8 {
+            ways[len][sum] = (0..=sum.min(4)).map(|c| ways[len - 1][sum - c]).sum();
+        }
+    }
+
+    // every way to hold five cards, once without a flush and, if the ranks
+    // are all different, once with
+    let mut hands: Vec<(EvaluatedHand, [u8; 13], bool)> = Vec::new();
+    for_each_rank_counts(5, &mut [0; 13], 0, &mut |counts| {
+        let ranks: Vec<Rank> = Rank::ALL
+            .iter()
+            .zip(counts)
+            .flat_map(|(rank, count)| std::iter::repeat_n(*rank, usize::from(*count)))
+            .collect();
+        // suits cycle so that no rank repeats a suit and there is no flush
+        let off_suit: Vec<Card> = ranks
+            .iter()
+            .enumerate()
+            .map(|(i, rank)| Card::new(*rank, Suit::ALL[i % 4]))
+            .collect();
+        hands.push((evaluate_five(&off_suit), *counts, false));
+        if counts.iter().all(|c| *c <= 1) {
+            let suited: Vec<Card> = ranks.iter().map(|r| Card::new(*r, Suit::Spade)).collect();
+            hands.push((evaluate_five(&suited), *counts, true));
+        }
+    });
+    hands.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+    debug_assert_eq!(hands.len(), usize::from(HAND_RANKS));
+
+    let mut flushes = vec![0; 1 << 13];
+    let mut unsuited5 = vec![0; ways[13][5] as usize];
+    for (i, (_, counts, flush)) in hands.iter().enumerate() {
+        let rank = i as u16 + 1;
+        if *flush {
+            flushes[mask_of(counts)] = rank;
+        } else {
+            unsuited5[rank_counts_index(&ways, counts)] = rank;
+        }
+    }
+
+    // six and seven card flushes play their best five suited cards
+    for mask in 0..flushes.len() {
+        if (6..=7).contains(&mask.count_ones()) {
+            flushes[mask] = submasks_of_five(mask)
+                .map(|five| flushes[five])
+                .max()
+                .unwrap_or(0);
+        }
+    }
+
+    // six and seven card hands play their best five cards
+    let mut unsuited = [unsuited5, Vec::new(), Vec::new()];
+    for n in 6..=7 {
+        let mut table = vec![0; ways[13][n] as usize];
+        for_each_rank_counts(n as u8, &mut [0; 13], 0, &mut |counts| {
+            let mut best = 0;
+            for_each_sub_counts(counts, 5, &mut [0; 13], 0, &mut |five| {
+                best = best.max(unsuited[0][rank_counts_index(&ways, five)]);
+            });
+            table[rank_counts_index(&ways, counts)] = best;
+        });
+        unsuited[n - 5] = table;
+    }
+
+    Tables {
+        flushes,
+        unsuited,
+        ways,
+    }
+}
+
+fn mask_of(counts: &[u8; 13]) -> usize {
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| **c > 0)
+        .fold(0, |mask, (i, _)| mask | 1 << i)
+}
+
+/// Every submask of `mask` with exactly five bits set.
+fn submasks_of_five(mask: usize) -> impl Iterator<Item = usize> {
+    (0..=mask).filter(move |sub| sub & !mask == 0 && sub.count_ones() == 5)
+}
+
+/// Calls `f` with every way of holding `n` cards with at most four of each
+/// rank, filling in `counts` from rank `from` upwards.
+fn for_each_rank_counts(n: u8, counts: &mut [u8; 13], from: usize, f: &mut impl FnMut(&[u8; 13])) {
+    if n == 0 {
+        f(counts);
+        return;
+    }
+    for rank in from..13 {
+        if counts[rank] < 4 {
+            counts[rank] += 1;
+            for_each_rank_counts(n - 1, counts, rank, f);
+            counts[rank] -= 1;
+        }
+    }
+}
+
+/// Calls `f` with every way of taking `n` cards from those held in `counts`,
+/// filling in `sub` from rank `from` upwards.
+fn for_each_sub_counts(
+    counts: &[u8; 13],
+    n: u8,
+    sub: &mut [u8; 13],
+    from: usize,
+    f: &mut impl FnMut(&[u8; 13]),
+) {
+    if n == 0 {
+        f(sub);
+        return;
+    }
+    for rank in from..13 {
+        if sub[rank] < counts[rank] {
+            sub[rank] += 1;
+            for_each_sub_counts(counts, n - 1, sub, rank, f);
+            sub[rank] -= 1;
+        }
+    }
+}
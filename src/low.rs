@@ -0,0 +1,52 @@
+use crate::{Card, Rank};
+
+/// An ace-to-five low hand that qualifies for the low half of a split pot:
+/// five cards of different ranks, none higher than eight, with aces counting
+/// as one. Straights and flushes do not count against a low.
+///
+/// Unlike [`EvaluatedHand`](crate::EvaluatedHand), lower lows compare as
+/// less and are better, just as they are at the table: `8-5-4-3-2` loses to
+/// `7-6-5-4-3`, and the wheel `5-4-3-2-A` is the best low possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LowHand {
+    // ace-low values, highest first
+    values: [u8; 5],
+}
+
+impl LowHand {
+    /// The ranks of the low, highest first, so an ace is always last.
+    pub fn ranks(&self) -> [Rank; 5] {
+        let mut ranks = [Rank::Ace; 5];
+        for (rank, value) in ranks.iter_mut().zip(self.values.iter()) {
+            if *value != 1 {
+                *rank = Rank::ALL[usize::from(value - 2)];
+            }
+        }
+        ranks
+    }
+}
+
+/// Value of a rank when aces are low: one for an ace, otherwise as usual.
+pub(crate) fn ace_low_value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 1,
+        rank => rank.value(),
+    }
+}
+
+/// Evaluates five cards as an eight-or-better low, or `None` if they do not
+/// qualify.
+pub(crate) fn eight_or_better(cards: &[Card]) -> Option<LowHand> {
+    let mut values = [0; 5];
+    for (value, card) in values.iter_mut().zip(cards) {
+        *value = ace_low_value(card.rank());
+    }
+    values.sort_unstable_by(|a, b| b.cmp(a));
+
+    let distinct = values.windows(2).all(|pair| pair[0] != pair[1]);
+    if distinct && values[0] <= 8 {
+        Some(LowHand { values })
+    } else {
+        None
+    }
+}
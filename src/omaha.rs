@@ -0,0 +1,54 @@
+use crate::hand::{check_card_count, check_distinct, evaluate_five, for_each_combination, join};
+use crate::low::eight_or_better;
+use crate::{BestHand, Card, HiLoHand, LowHand, PokerError};
+
+/// Evaluates an Omaha hand, which must use exactly two of its hole cards and
+/// exactly three cards from the board.
+///
+/// Four hole cards is standard Omaha; five and six are accepted for PLO5 and
+/// PLO6. The board may have three to five cards. Errors use the position of
+/// the offending card in the hole cards followed by the board as their
+/// offset.
+pub fn evaluate_omaha(hole: &[Card], board: &[Card]) -> Result<BestHand, PokerError> {
+    Ok(evaluate_omaha_hi_lo(hole, board)?.high)
+}
+
+/// Evaluates an Omaha Hi/Lo (Omaha eight-or-better) hand, giving the best
+/// high hand and, when one can be made, the best qualifying low.
+///
+/// The two halves are chosen independently, so they may use different hole
+/// cards. The card rules are those of [`evaluate_omaha`].
+pub fn evaluate_omaha_hi_lo(hole: &[Card], board: &[Card]) -> Result<HiLoHand, PokerError> {
+    let cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+    check_distinct(&cards)?;
+    check_card_count(hole.len(), 4..=6, || join(hole))?;
+    check_card_count(board.len(), 3..=5, || join(board))?;
+
+    let mut high: Option<BestHand> = None;
+    let mut low: Option<BestHand<LowHand>> = None;
+    let mut five = Vec::with_capacity(5);
+
+    for_each_combination(hole, 2, |from_hole| {
+        for_each_combination(board, 3, |from_board| {
+            five.clear();
+            five.extend_from_slice(from_hole);
+            five.extend_from_slice(from_board);
+
+            let hand = evaluate_five(&five);
+            if high.as_ref().is_none_or(|best| hand > *best.hand()) {
+                high = Some(BestHand::new(hand, &five));
+            }
+
+            if let Some(hand) = eight_or_better(&five) {
+                if low.as_ref().is_none_or(|best| hand < *best.hand()) {
+                    low = Some(BestHand::new(hand, &five));
+                }
+            }
+        });
+    });
+
+    Ok(HiLoHand {
+        high: high.expect("there are enough cards for a hand"),
+        low,
+    })
+}
@@ -0,0 +1,837 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::history::parse_history_card;
+use crate::{
+    evaluate_holdem, settle, Action, Betting, Blinds, Card, Contribution, OddChip, PokerError,
+    Rank, RankingRules, Settlement, Street, TableHand,
+};
+
+/// A hand in the Poker Hand History (PHH) format, the open TOML-based format
+/// shared by open-source poker tools, such as:
+///
+/// ```text
+/// variant = "NT"
+/// antes = [0, 0, 0]
+/// blinds_or_straddles = [1, 2, 0]
+/// min_bet = 2
+/// starting_stacks = [200, 200, 200]
+/// actions = [
+///   "d dh p1 AcKs",
+///   "d dh p2 ????",
+///   "d dh p3 7h6h",
+///   "p3 cbr 6",
+///   "p1 f",
+///   "p2 cc",
+///   "d db 2c7dKs",
+/// ]
+/// ```
+///
+/// `FromStr` reads it and `Display` writes it. Players are in the order of
+/// the format, starting left of the button, which is the last player; PHH
+/// calls them `p1`, `p2` and so on, but here they are numbered from 0.
+/// Amounts must be whole numbers of chips. Fields other than those below are
+/// skipped when reading.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhhHand {
+    /// The game code, such as `NT` for no-limit Texas Hold'em.
+    pub variant: String,
+    pub ante_trimming_status: bool,
+    pub antes: Vec<u64>,
+    /// What each player posts before the cards are dealt. Heads-up, as in
+    /// the format's reference implementation, the two are swapped, so that
+    /// `[1, 2]` has the button post the small blind.
+    pub blinds_or_straddles: Vec<u64>,
+    pub min_bet: u64,
+    pub starting_stacks: Vec<u64>,
+    pub actions: Vec<PhhAction>,
+    /// The players' names, if known.
+    pub players: Vec<String>,
+    pub finishing_stacks: Option<Vec<u64>>,
+}
+
+/// One action of a [`PhhHand`], by the dealer or a player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PhhAction {
+    /// `d dh p1 AcKs`: hole cards dealt to a player, `None` for each card
+    /// written `??` because it is unknown.
+    DealHole {
+        player: usize,
+        cards: Vec<Option<Card>>,
+    },
+    /// `d db 2c7dKs`: community cards.
+    DealBoard { cards: Vec<Card> },
+    /// `p1 f`
+    Fold { player: usize },
+    /// `p1 cc`
+    CheckOrCall { player: usize },
+    /// `p1 cbr 300`: a bet or raise to `to` chips on the street.
+    BetOrRaise { player: usize, to: u64 },
+    /// `p1 sm AcKs`: the player shows their cards, or mucks if there are
+    /// none.
+    ShowOrMuck {
+        player: usize,
+        cards: Vec<Option<Card>>,
+    },
+}
+
+impl PhhHand {
+    /// The blinds for a game with one ante for everyone and no straddles.
+    fn blinds(&self) -> Result<Blinds, PokerError> {
+        let invalid = |field: &str| PokerError::InvalidHistory {
+            offset: 0,
+            token: field.to_string(),
+        };
+        let ante = self.antes.first().copied().unwrap_or(0);
+        if self.antes.iter().any(|a| *a != ante) {
+            return Err(invalid("antes"));
+        }
+        match self.blinds_or_straddles.as_slice() {
+            [small, big, rest @ ..] if rest.iter().all(|s| *s == 0) => Ok(Blinds {
+                small: *small,
+                big: *big,
+                ante,
+            }),
+            _ => Err(invalid("blinds_or_straddles")),
+        }
+    }
+
+    /// Plays the actions through [`Betting`], with bets and raises of at
+    /// least `min_bet`, then shares out the pot with the crate's own hand
+    /// comparison deciding the showdown.
+    ///
+    /// Only no-limit Hold'em (`NT`) with one ante for everyone and no
+    /// straddles can be replayed; anything else is an
+    /// [`InvalidHistory`](PokerError::InvalidHistory) error naming the
+    /// field. An action that is out of turn or not allowed is an
+    /// `InvalidHistory` error with the action's index as its offset, as is a
+    /// show of only some cards, such as `p1 sm Th??`, and a hand that stops
+    /// before it is over, at the index past the last action. A player who
+    /// reaches the showdown without a show or muck plays the hole cards
+    /// dealt to them, if they are known.
+    pub fn settle(&self) -> Result<Settlement, PokerError> {
+        if self.variant != "NT" {
+            return Err(PokerError::InvalidHistory {
+                offset: 0,
+                token: self.variant.clone(),
+            });
+        }
+        let players = self.starting_stacks.len();
+        let blinds = self.blinds()?;
+        if players < 2 || self.blinds_or_straddles.len() != players {
+            return Err(PokerError::InvalidHistory {
+                offset: 0,
+                token: "starting_stacks".to_string(),
+            });
+        }
+
+        let mut betting =
+            Betting::with_min_bet(&self.starting_stacks, players - 1, blinds, self.min_bet);
+        let mut board = Vec::new();
+        let mut dealt = vec![None; players];
+        let mut shown = vec![None; players];
+        let mut mucked = vec![false; players];
+        // the blinds may leave no one able to bet
+        while betting.to_act().is_none() && !betting.is_hand_over() {
+            betting.next_street().expect("the round is complete");
+        }
+        for (i, entry) in self.actions.iter().enumerate() {
+            let invalid = || PokerError::InvalidHistory {
+                offset: i,
+                token: entry.to_string(),
+            };
+            let (player, action) = match entry {
+                PhhAction::DealHole { player, cards } => {
+                    let known: Option<Vec<Card>> = cards.iter().copied().collect();
+                    *dealt.get_mut(*player).ok_or_else(invalid)? = known;
+                    continue;
+                }
+                PhhAction::DealBoard { cards } => {
+                    board.extend(cards);
+                    continue;
+                }
+                PhhAction::ShowOrMuck { player, cards } => {
+                    if cards.is_empty() {
+                        *mucked.get_mut(*player).ok_or_else(invalid)? = true;
+                    } else {
+                        // a hand shown only in part cannot be judged
+                        let known: Option<Vec<Card>> = cards.iter().copied().collect();
+                        *shown.get_mut(*player).ok_or_else(invalid)? =
+                            Some(known.ok_or_else(invalid)?);
+                    }
+                    continue;
+                }
+                PhhAction::Fold { player } => (*player, Action::Fold),
+                PhhAction::CheckOrCall { player } => {
+                    let legal = betting.legal_actions().ok_or_else(invalid)?;
+                    if legal.can_check() {
+                        (*player, Action::Check)
+                    } else {
+                        (*player, Action::Call)
+                    }
+                }
+                PhhAction::BetOrRaise { player, to } if betting.current_bet() == 0 => {
+                    (*player, Action::Bet(*to))
+                }
+                PhhAction::BetOrRaise { player, to } => (*player, Action::Raise(*to)),
+            };
+            if betting.to_act() != Some(player) {
+                return Err(invalid());
+            }
+            betting.act(action).map_err(|_| invalid())?;
+            while betting.to_act().is_none() && !betting.is_hand_over() {
+                betting.next_street().map_err(|_| invalid())?;
+            }
+        }
+        if !betting.is_hand_over() {
+            return Err(PokerError::InvalidHistory {
+                offset: self.actions.len(),
+                token: String::new(),
+            });
+        }
+
+        // chips no one else matched go back to the player, even one who
+        // mucks rather than showing down
+        let contributed = betting.contributed();
+        let uncalled: Vec<u64> = (0..players)
+            .map(|player| {
+                let matched = (0..players)
+                    .filter(|other| *other != player)
+                    .map(|other| contributed[other])
+                    .max()
+                    .unwrap_or(0);
+                contributed[player].saturating_sub(matched)
+            })
+            .collect();
+        let contributions = (0..players)
+            .map(|player| {
+                // with no show recorded, hole cards dealt face up play
+                let hand = match shown[player].as_ref().or(dealt[player].as_ref()) {
+                    Some(hole) if betting.street() == Street::Showdown && !mucked[player] => {
+                        Some(evaluate_holdem(hole, &board)?.cards().to_vec())
+                    }
+                    _ => None,
+                };
+                Ok(Contribution {
+                    chips: contributed[player] - uncalled[player],
+                    folded: betting.folded()[player] || mucked[player],
+                    hand,
+                })
+            })
+            .collect::<Result<Vec<Contribution>, PokerError>>()?;
+        let mut settlement = settle(
+            &contributions,
+            RankingRules::High,
+            OddChip::LeftOfButton {
+                button: players - 1,
+            },
+        )?;
+        for (player, chips) in uncalled.into_iter().enumerate() {
+            if chips > 0 {
+                settlement.return_uncalled(player, chips);
+            }
+        }
+        Ok(settlement)
+    }
+}
+
+impl From<&TableHand> for PhhHand {
+    /// Records a hand played at a [`Table`](crate::Table), with every hole
+    /// card known. Finishing stacks are included once the hand is over.
+    fn from(hand: &TableHand) -> PhhHand {
+        let betting = hand.betting();
+        let players = hand.seats().len();
+        // the format starts left of the button
+        let order: Vec<usize> = (1..=players)
+            .map(|i| (betting.button() + i) % players)
+            .collect();
+        let starting_stacks: Vec<u64> = order
+            .iter()
+            .map(|p| betting.stacks()[*p] + betting.contributed()[*p])
+            .collect();
+        let blinds = hand.blinds();
+        let mut blinds_or_straddles = vec![0; players];
+        blinds_or_straddles[0] = blinds.small;
+        blinds_or_straddles[1] = blinds.big;
+
+        let mut actions: Vec<PhhAction> = order
+            .iter()
+            .enumerate()
+            .map(|(player, p)| PhhAction::DealHole {
+                player,
+                cards: hand.hole_cards()[*p].iter().copied().map(Some).collect(),
+            })
+            .collect();
+
+        // replay the betting in the format's order to put amounts on all-ins
+        // and deal the board where it fell
+        let mut replay = Betting::new(&starting_stacks, players - 1, blinds);
+        let mut dealt = 0;
+        let mut deal_board = |replay: &mut Betting, actions: &mut Vec<PhhAction>| {
+            while replay.to_act().is_none() && !replay.is_hand_over() {
+                let cards = match replay.next_street().expect("the round is complete") {
+                    Street::Flop => 3,
+                    Street::Turn | Street::River => 1,
+                    Street::Preflop | Street::Showdown => continue,
+                };
+                actions.push(PhhAction::DealBoard {
+                    cards: hand.board()[dealt..dealt + cards].to_vec(),
+                });
+                dealt += cards;
+            }
+        };
+        deal_board(&mut replay, &mut actions);
+        for (_, p, action) in hand.actions() {
+            let player = order
+                .iter()
+                .position(|q| q == p)
+                .expect("a player in the hand");
+            let all_in = replay.bets()[player] + replay.stacks()[player];
+            actions.push(match action {
+                Action::Fold => PhhAction::Fold { player },
+                Action::Check | Action::Call => PhhAction::CheckOrCall { player },
+                Action::Bet(to) | Action::Raise(to) => PhhAction::BetOrRaise { player, to: *to },
+                Action::AllIn if all_in > replay.current_bet() => {
+                    PhhAction::BetOrRaise { player, to: all_in }
+                }
+                Action::AllIn => PhhAction::CheckOrCall { player },
+            });
+            replay.act(*action).expect("the hand's own actions replay");
+            deal_board(&mut replay, &mut actions);
+        }
+
+        if hand.street() == Street::Showdown {
+            for (player, p) in order.iter().enumerate() {
+                if !betting.folded()[*p] {
+                    actions.push(PhhAction::ShowOrMuck {
+                        player,
+                        cards: hand.hole_cards()[*p].iter().copied().map(Some).collect(),
+                    });
+                }
+            }
+        }
+
+        let finishing_stacks = hand.settlement().map(|settlement| {
+            order
+                .iter()
+                .map(|p| betting.stacks()[*p] + settlement.payouts()[*p])
+                .collect()
+        });
+        PhhHand {
+            variant: "NT".to_string(),
+            ante_trimming_status: false,
+            antes: vec![blinds.ante; players],
+            blinds_or_straddles,
+            min_bet: blinds.big.max(1),
+            starting_stacks,
+            actions,
+            players: Vec::new(),
+            finishing_stacks,
+        }
+    }
+}
+
+impl FromStr for PhhAction {
+    type Err = PokerError;
+
+    /// Parses one action, such as `p2 cbr 300` or `d db Qh`. A trailing
+    /// `#` comment is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_action(s, 0)
+    }
+}
+
+impl fmt::Display for PhhAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PhhAction::DealHole { player, cards } => {
+                write!(f, "d dh p{} ", player + 1)?;
+                write_cards(f, cards)
+            }
+            PhhAction::DealBoard { cards } => {
+                f.write_str("d db ")?;
+                write_cards(f, &cards.iter().copied().map(Some).collect::<Vec<_>>())
+            }
+            PhhAction::Fold { player } => write!(f, "p{} f", player + 1),
+            PhhAction::CheckOrCall { player } => write!(f, "p{} cc", player + 1),
+            PhhAction::BetOrRaise { player, to } => write!(f, "p{} cbr {}", player + 1, to),
+            PhhAction::ShowOrMuck { player, cards } => {
+                write!(f, "p{} sm", player + 1)?;
+                if !cards.is_empty() {
+                    f.write_str(" ")?;
+                    write_cards(f, cards)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Writes cards run together as the format has them, such as `AcTs??`.
+fn write_cards(f: &mut fmt::Formatter, cards: &[Option<Card>]) -> fmt::Result {
+    for card in cards {
+        match card {
+            Some(card) => {
+                match card.rank() {
+                    Rank::Ten => f.write_str("T")?,
+                    rank => write!(f, "{}", rank)?,
+                }
+                write!(f, "{}", card.suit().to_string().to_lowercase())?;
+            }
+            None => f.write_str("??")?,
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for PhhHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let list = |values: &[u64]| {
+            let values: Vec<String> = values.iter().map(u64::to_string).collect();
+            format!("[{}]", values.join(", "))
+        };
+        writeln!(f, "variant = {}", quote(&self.variant))?;
+        if self.ante_trimming_status {
+            writeln!(f, "ante_trimming_status = true")?;
+        }
+        writeln!(f, "antes = {}", list(&self.antes))?;
+        writeln!(
+            f,
+            "blinds_or_straddles = {}",
+            list(&self.blinds_or_straddles)
+        )?;
+        writeln!(f, "min_bet = {}", self.min_bet)?;
+        writeln!(f, "starting_stacks = {}", list(&self.starting_stacks))?;
+        writeln!(f, "actions = [")?;
+        for action in &self.actions {
+            writeln!(f, "  {},", quote(&action.to_string()))?;
+        }
+        writeln!(f, "]")?;
+        if !self.players.is_empty() {
+            let names: Vec<String> = self.players.iter().map(|name| quote(name)).collect();
+            writeln!(f, "players = [{}]", names.join(", "))?;
+        }
+        if let Some(stacks) = &self.finishing_stacks {
+            writeln!(f, "finishing_stacks = {}", list(stacks))?;
+        }
+        Ok(())
+    }
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl FromStr for PhhHand {
+    type Err = PokerError;
+
+    /// Reads a hand from the TOML subset PHH files use: `key = value` lines
+    /// of strings, whole numbers, booleans and arrays of them, with
+    /// comments. Keys under a `[table]` header are skipped. Errors carry the
+    /// byte offset of the offending value and its text, or the name of a
+    /// missing field at offset 0.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = read_toml(s)?;
+        let field = |name: &str| fields.iter().find(|(key, _)| key == name).map(|(_, v)| v);
+        let required = |name: &str| {
+            field(name).ok_or_else(|| PokerError::InvalidHistory {
+                offset: 0,
+                token: name.to_string(),
+            })
+        };
+
+        let starting_stacks = required("starting_stacks")?.amounts(s)?;
+        let players = starting_stacks.len();
+        let one_each = |value: &Value, count: usize| {
+            if count == players {
+                Ok(())
+            } else {
+                Err(value.invalid(s))
+            }
+        };
+        let amounts_each = |value: &Value| {
+            let amounts = value.amounts(s)?;
+            one_each(value, amounts.len())?;
+            Ok(amounts)
+        };
+        let per_player = |name: &str| amounts_each(required(name)?);
+
+        let actions = required("actions")?
+            .array(s)?
+            .iter()
+            .map(|value| {
+                // offsets inside the action count from after the opening quote
+                let action = parse_action(&value.string(s)?, value.start + 1)?;
+                match action {
+                    PhhAction::DealHole { player, .. }
+                    | PhhAction::Fold { player }
+                    | PhhAction::CheckOrCall { player }
+                    | PhhAction::BetOrRaise { player, .. }
+                    | PhhAction::ShowOrMuck { player, .. }
+                        if player >= players =>
+                    {
+                        Err(value.invalid(s))
+                    }
+                    action => Ok(action),
+                }
+            })
+            .collect::<Result<Vec<PhhAction>, PokerError>>()?;
+
+        Ok(PhhHand {
+            variant: required("variant")?.string(s)?,
+            ante_trimming_status: match field("ante_trimming_status") {
+                Some(value) => value.boolean(s)?,
+                None => false,
+            },
+            antes: per_player("antes")?,
+            blinds_or_straddles: per_player("blinds_or_straddles")?,
+            min_bet: required("min_bet")?.amount(s)?,
+            starting_stacks,
+            actions,
+            players: match field("players") {
+                Some(value) => {
+                    let names = value.array(s)?;
+                    one_each(value, names.len())?;
+                    names
+                        .iter()
+                        .map(|name| name.string(s))
+                        .collect::<Result<Vec<String>, PokerError>>()?
+                }
+                None => Vec::new(),
+            },
+            finishing_stacks: field("finishing_stacks").map(amounts_each).transpose()?,
+        })
+    }
+}
+
+/// Parses one action found at byte `offset`.
+fn parse_action(s: &str, offset: usize) -> Result<PhhAction, PokerError> {
+    let invalid = || PokerError::InvalidHistory {
+        offset,
+        token: s.to_string(),
+    };
+    let text = s.split('#').next().unwrap_or(s);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let player = |word: &str| {
+        word.strip_prefix('p')
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|n| *n > 0)
+            .map(|n| n - 1)
+            .ok_or_else(invalid)
+    };
+    let cards = |word: &str| {
+        parse_phh_cards(
+            word,
+            offset + (word.as_ptr() as usize - s.as_ptr() as usize),
+        )
+    };
+    let action = match words.as_slice() {
+        ["d", "dh", p, hole] => PhhAction::DealHole {
+            player: player(p)?,
+            cards: cards(hole)?,
+        },
+        ["d", "db", board] => PhhAction::DealBoard {
+            cards: cards(board)?
+                .into_iter()
+                .collect::<Option<Vec<Card>>>()
+                .ok_or_else(invalid)?,
+        },
+        [p, "f"] => PhhAction::Fold { player: player(p)? },
+        [p, "cc"] => PhhAction::CheckOrCall { player: player(p)? },
+        [p, "cbr", to] => PhhAction::BetOrRaise {
+            player: player(p)?,
+            to: to.parse().map_err(|_| invalid())?,
+        },
+        [p, "sm"] => PhhAction::ShowOrMuck {
+            player: player(p)?,
+            cards: Vec::new(),
+        },
+        [p, "sm", shown] => PhhAction::ShowOrMuck {
+            player: player(p)?,
+            cards: cards(shown)?,
+        },
+        _ => return Err(invalid()),
+    };
+    Ok(action)
+}
+
+/// Parses cards run together, such as `AcKs` or `????`, found at byte
+/// `offset`.
+fn parse_phh_cards(s: &str, offset: usize) -> Result<Vec<Option<Card>>, PokerError> {
+    if !s.is_ascii() || !s.len().is_multiple_of(2) {
+        return Err(PokerError::InvalidHistory {
+            offset,
+            token: s.to_string(),
+        });
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| match &s[i..i + 2] {
+            "??" => Ok(None),
+            card => parse_history_card(card, offset + i).map(Some),
+        })
+        .collect()
+}
+
+/// A TOML value and the span of text it came from.
+struct Value {
+    start: usize,
+    end: usize,
+    kind: Kind,
+}
+
+enum Kind {
+    String(String),
+    Integer(u64),
+    Boolean(bool),
+    Array(Vec<Value>),
+    /// Any other value, such as a float or a date, which PHH fields read
+    /// here never hold.
+    Other,
+}
+
+impl Value {
+    fn invalid(&self, text: &str) -> PokerError {
+        PokerError::InvalidHistory {
+            offset: self.start,
+            token: text[self.start..self.end].to_string(),
+        }
+    }
+
+    fn string(&self, text: &str) -> Result<String, PokerError> {
+        match &self.kind {
+            Kind::String(s) => Ok(s.clone()),
+            _ => Err(self.invalid(text)),
+        }
+    }
+
+    fn amount(&self, text: &str) -> Result<u64, PokerError> {
+        match self.kind {
+            Kind::Integer(n) => Ok(n),
+            _ => Err(self.invalid(text)),
+        }
+    }
+
+    fn boolean(&self, text: &str) -> Result<bool, PokerError> {
+        match self.kind {
+            Kind::Boolean(b) => Ok(b),
+            _ => Err(self.invalid(text)),
+        }
+    }
+
+    fn array(&self, text: &str) -> Result<&[Value], PokerError> {
+        match &self.kind {
+            Kind::Array(values) => Ok(values),
+            _ => Err(self.invalid(text)),
+        }
+    }
+
+    fn amounts(&self, text: &str) -> Result<Vec<u64>, PokerError> {
+        self.array(text)?.iter().map(|v| v.amount(text)).collect()
+    }
+}
+
+/// Reads the top-level `key = value` pairs of a TOML document.
+fn read_toml(text: &str) -> Result<Vec<(String, Value)>, PokerError> {
+    let mut reader = Reader { text, pos: 0 };
+    let mut fields = Vec::new();
+    let mut in_table = false;
+    loop {
+        reader.skip_blank(true);
+        match reader.peek() {
+            None => return Ok(fields),
+            Some('[') => {
+                in_table = true;
+                reader.skip_line();
+                continue;
+            }
+            Some(_) => {}
+        }
+        let key = reader.key()?;
+        reader.skip_blank(false);
+        reader.expect('=')?;
+        reader.skip_blank(false);
+        let value = reader.value()?;
+        reader.skip_blank(false);
+        if !matches!(reader.peek(), None | Some('\n') | Some('\r')) {
+            return Err(reader.invalid(reader.pos));
+        }
+        if !in_table {
+            fields.push((key, value));
+        }
+    }
+}
+
+struct Reader<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// An error for the text from `start` to the end of its line.
+    fn invalid(&self, start: usize) -> PokerError {
+        let line = self.text[start..].lines().next().unwrap_or("");
+        PokerError::InvalidHistory {
+            offset: start,
+            token: line.to_string(),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), PokerError> {
+        if self.peek() == Some(c) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.invalid(self.pos))
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while !matches!(self.peek(), None | Some('\n')) {
+            self.bump();
+        }
+    }
+
+    /// Skips spaces and comments, and line breaks too if `newlines` is set.
+    fn skip_blank(&mut self, newlines: bool) {
+        loop {
+            match self.peek() {
+                Some(' ') | Some('\t') => {}
+                Some('\r') | Some('\n') if newlines => {}
+                Some('#') => {
+                    self.skip_line();
+                    continue;
+                }
+                _ => return,
+            }
+            self.bump();
+        }
+    }
+
+    fn key(&mut self) -> Result<String, PokerError> {
+        if self.peek() == Some('"') {
+            return self.string();
+        }
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            self.bump();
+        }
+        if self.pos == start {
+            return Err(self.invalid(start));
+        }
+        Ok(self.text[start..self.pos].to_string())
+    }
+
+    fn value(&mut self) -> Result<Value, PokerError> {
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some('"') | Some('\'') => Kind::String(self.string()?),
+            Some('[') => Kind::Array(self.array()?),
+            _ => {
+                while !matches!(
+                    self.peek(),
+                    None | Some(',')
+                        | Some(']')
+                        | Some('#')
+                        | Some(' ')
+                        | Some('\t')
+                        | Some('\r')
+                        | Some('\n')
+                ) {
+                    self.bump();
+                }
+                match &self.text[start..self.pos] {
+                    "" => return Err(self.invalid(start)),
+                    "true" => Kind::Boolean(true),
+                    "false" => Kind::Boolean(false),
+                    word => match word.replace('_', "").parse() {
+                        Ok(n) => Kind::Integer(n),
+                        Err(_) => Kind::Other,
+                    },
+                }
+            }
+        };
+        Ok(Value {
+            start,
+            end: self.pos,
+            kind,
+        })
+    }
+
+    fn array(&mut self) -> Result<Vec<Value>, PokerError> {
+        let start = self.pos;
+        self.expect('[')?;
+        let mut values = Vec::new();
+        loop {
+            self.skip_blank(true);
+            if self.peek() == Some(']') {
+                self.bump();
+                return Ok(values);
+            }
+            values.push(self.value()?);
+            self.skip_blank(true);
+            match self.bump() {
+                Some(',') => {}
+                Some(']') => return Ok(values),
+                _ => return Err(self.invalid(start)),
+            }
+        }
+    }
+
+    /// Reads a basic `"..."` string with escapes, or a literal `'...'` one.
+    fn string(&mut self) -> Result<String, PokerError> {
+        let start = self.pos;
+        let quote = self.bump();
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(self.invalid(start)),
+                Some(c) if Some(c) == quote => return Ok(s),
+                Some('\\') if quote == Some('"') => {
+                    let escaped = match self.bump() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('u') => self.unicode(4).ok_or_else(|| self.invalid(start))?,
+                        Some('U') => self.unicode(8).ok_or_else(|| self.invalid(start))?,
+                        _ => return Err(self.invalid(start)),
+                    };
+                    s.push(escaped);
+                }
+                Some(c) => s.push(c),
+            }
+        }
+    }
+
+    fn unicode(&mut self, digits: usize) -> Option<char> {
+        let hex = self.text.get(self.pos..self.pos + digits)?;
+        self.pos += digits;
+        char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+    }
+}
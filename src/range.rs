@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::{Card, CardSet, PokerError, Rank, Suit};
+
+/// A weighted set of two-card starting hands, as written in range notation.
+///
+/// A range is a comma separated list of entries, each one of:
+///
+/// | Entry | Hands |
+/// |---|---|
+/// | `TT`, `AKs`, `AKo`, `AK` | a pair, suited, offsuit, or any two cards of those ranks |
+/// | `TT+` | that pair and every higher pair |
+/// | `A2s+` | raising the kicker up to one below the top card: `A2s` to `AKs` |
+/// | `76s+` | for connectors, raising both cards: `76s`, `87s`, ... `AKs` |
+/// | `22-55`, `A2s-A5s`, `76s-T9s` | every hand between the two ends, which must share a top card or a gap |
+/// | `AhKh` | one exact combination, with lowercase or uppercase suits |
+///
+/// Ranks are `2`-`9`, `T` (or `10`), `J`, `Q`, `K` and `A`. Any entry may end
+/// with a weight between 0 and 1, as in `AKo:0.5`, giving the fraction of
+/// the time the hand is played. A hand named again takes its latest weight,
+/// and a weight of 0 removes it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Range {
+    /// Weights by combination, higher card first.
+    combos: BTreeMap<[Card; 2], f64>,
+}
+
+impl Range {
+    pub fn new() -> Range {
+        Range::default()
+    }
+
+    /// The number of distinct combinations in the range.
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Every combination in the range with its weight, higher card first.
+    pub fn iter(&self) -> impl Iterator<Item = ([Card; 2], f64)> + '_ {
+        self.combos.iter().map(|(combo, weight)| (*combo, *weight))
+    }
+
+    /// The weight of the combination of `a` and `b`, in either order, or 0
+    /// if it is not in the range.
+    pub fn weight(&self, a: Card, b: Card) -> f64 {
+        self.combos.get(&combo(a, b)).copied().unwrap_or(0.0)
+    }
+
+    /// The range left once `known` cards, such as the board or dead cards,
+    /// can no longer be dealt: every combination holding one of them is
+    /// dropped.
+    pub fn without(&self, known: CardSet) -> Range {
+        Range {
+            combos: self
+                .combos
+                .iter()
+                .filter(|(combo, _)| !combo.iter().any(|card| known.contains(*card)))
+                .map(|(combo, weight)| (*combo, *weight))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Range {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range = Range::new();
+        if s.trim().is_empty() {
+            return Ok(range);
+        }
+
+        let mut start = 0;
+        for entry in s.split(',') {
+            let offset = start + entry.len() - entry.trim_start().len();
+            start += entry.len() + 1;
+            let entry = entry.trim();
+            let (combos, weight) = parse_entry(entry).ok_or_else(|| PokerError::InvalidRange {
+                offset,
+                token: entry.to_string(),
+            })?;
+            for combo in combos {
+                if weight > 0.0 {
+                    range.combos.insert(combo, weight);
+                } else {
+                    range.combos.remove(&combo);
+                }
+            }
+        }
+        Ok(range)
+    }
+}
+
+/// Two ranks and, for non-pairs, whether the cards are suited (`None` for
+/// either).
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Class {
+    high: Rank,
+    low: Rank,
+    suited: Option<bool>,
+}
+
+impl Class {
+    fn is_pair(self) -> bool {
+        self.high == self.low
+    }
+
+    fn gap(self) -> u8 {
+        self.high.value() - self.low.value()
+    }
+
+    /// The same kind of hand with both ranks moved up by `by`.
+    fn shifted(self, by: u8) -> Option<Class> {
+        Some(Class {
+            high: shift(self.high, by)?,
+            low: shift(self.low, by)?,
+            ..self
+        })
+    }
+
+    /// The same kind of hand with the lower card replaced.
+    fn with_low(self, low: Rank) -> Class {
+        Class { low, ..self }
+    }
+
+    fn combos(self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::new();
+        for (i, high_suit) in Suit::ALL.iter().enumerate() {
+            for (j, low_suit) in Suit::ALL.iter().enumerate() {
+                let wanted = if self.is_pair() {
+                    i < j
+                } else {
+                    self.suited.is_none_or(|suited| suited == (i == j))
+                };
+                if wanted {
+                    combos.push(combo(
+                        Card::new(self.high, *high_suit),
+                        Card::new(self.low, *low_suit),
+                    ));
+                }
+            }
+        }
+        combos
+    }
+}
+
+fn combo(a: Card, b: Card) -> [Card; 2] {
+    if a > b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+fn shift(rank: Rank, by: u8) -> Option<Rank> {
+    Rank::try_from(rank.value() + by).ok()
+}
+
+fn parse_entry(entry: &str) -> Option<(Vec<[Card; 2]>, f64)> {
+    let (body, weight) = match entry.split_once(':') {
+        Some((body, weight)) => {
+            let weight: f64 = weight.trim().parse().ok()?;
+            if !(0.0..=1.0).contains(&weight) {
+                return None;
+            }
+            (body.trim_end(), weight)
+        }
+        None => (entry, 1.0),
+    };
+
+    let classes = if let Some((from, to)) = body.split_once('-') {
+        span(parse_class(from.trim())?, parse_class(to.trim())?)?
+    } else if let Some(class) = body.strip_suffix('+') {
+        plus(parse_class(class)?)
+    } else if let Some(class) = parse_class(body) {
+        vec![class]
+    } else {
+        return Some((vec![parse_exact(body)?], weight));
+    };
+    Some((
+        classes.into_iter().flat_map(Class::combos).collect(),
+        weight,
+    ))
+}
+
+/// Every hand between two ends, which must be pairs, share a top card or
+/// share a gap.
+fn span(from: Class, to: Class) -> Option<Vec<Class>> {
+    if from.suited != to.suited || from.is_pair() != to.is_pair() {
+        return None;
+    }
+    let (from, to) = if from.low <= to.low {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let steps = to.low.value() - from.low.value();
+    if from.high == to.high && !from.is_pair() {
+        (0..=steps)
+            .map(|by| Some(from.with_low(shift(from.low, by)?)))
+            .collect()
+    } else if from.gap() == to.gap() {
+        (0..=steps).map(|by| from.shifted(by)).collect()
+    } else {
+        None
+    }
+}
+
+/// A hand and every better hand of the same shape.
+fn plus(class: Class) -> Vec<Class> {
+    if class.is_pair() || class.gap() == 1 {
+        (0..).map_while(|by| class.shifted(by)).collect()
+    } else {
+        (0..class.gap())
+            .filter_map(|by| Some(class.with_low(shift(class.low, by)?)))
+            .collect()
+    }
+}
+
+/// Parses two ranks, in either order, and an optional `s` or `o`.
+fn parse_class(s: &str) -> Option<Class> {
+    let (first, rest) = parse_rank(s)?;
+    let (second, rest) = parse_rank(rest)?;
+    let suited = match rest {
+        "" => None,
+        "s" | "S" => Some(true),
+        "o" | "O" => Some(false),
+        _ => return None,
+    };
+    if first == second && suited.is_some() {
+        return None;
+    }
+    Some(Class {
+        high: first.max(second),
+        low: first.min(second),
+        suited,
+    })
+}
+
+/// Parses two exact cards such as `AhKh`.
+fn parse_exact(s: &str) -> Option<[Card; 2]> {
+    let (first, rest) = parse_rank(s)?;
+    let (first_suit, rest) = parse_suit(rest)?;
+    let (second, rest) = parse_rank(rest)?;
+    let (second_suit, rest) = parse_suit(rest)?;
+    let (a, b) = (Card::new(first, first_suit), Card::new(second, second_suit));
+    if !rest.is_empty() || a == b {
+        return None;
+    }
+    Some(combo(a, b))
+}
+
+fn parse_rank(s: &str) -> Option<(Rank, &str)> {
+    if let Some(rest) = s.strip_prefix("10") {
+        return Some((Rank::Ten, rest));
+    }
+    let mut chars = s.chars();
+    let rank = match chars.next()?.to_ascii_uppercase() {
+        c @ '2'..='9' => Rank::try_from(c as u8 - b'0').ok()?,
+        'T' => Rank::Ten,
+        'J' => Rank::Jack,
+        'Q' => Rank::Queen,
+        'K' => Rank::King,
+        'A' => Rank::Ace,
+        _ => return None,
+    };
+    Some((rank, chars.as_str()))
+}
+
+fn parse_suit(s: &str) -> Option<(Suit, &str)> {
+    let mut chars = s.chars();
+    let suit = match chars.next()?.to_ascii_lowercase() {
+        'c' => Suit::Club,
+        'd' => Suit::Diamond,
+        'h' => Suit::Heart,
+        's' => Suit::Spade,
+        _ => return None,
+    };
+    Some((suit, chars.as_str()))
+}
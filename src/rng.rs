@@ -0,0 +1,43 @@
+/// A small seeded pseudo-random generator (SplitMix64).
+///
+/// It is fast, has no dependencies and gives the same sequence on every
+/// platform for a given seed, which is all simulations and shuffles need.
+/// It is not suitable for anything that must be unpredictable.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed number below `n`, which must not be zero.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        // multiply-shift maps 64 random bits onto 0..n with negligible bias
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// A uniformly distributed number in `0.0..1.0`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Moves `n` randomly chosen items to the front of `items`, in random
+    /// order, leaving the rest in an unspecified order.
+    pub(crate) fn partial_shuffle<T>(&mut self, items: &mut [T], n: usize) {
+        for i in 0..n.min(items.len()) {
+            let j = i + self.below(items.len() - i);
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::cmp::Ordering;
+
+use crate::hand::{
+    check_card_count, check_distinct, evaluate_five_with, join, parse_hand, tokenize,
+};
+use crate::holdem::best_of;
+use crate::low::ace_low_value;
+use crate::{BestHand, Card, EvaluatedHand, HandType, PokerError, Rank};
+
+/// Which hands win a showdown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum RankingRules {
+    /// Standard high-hand poker. Aces are high, but can also start a
+    /// straight (the wheel, `A-2-3-4-5`).
+    #[default]
+    High,
+    /// Ace-to-five lowball, as in Razz: the lowest hand wins, aces are always
+    /// low and straights and flushes are ignored, so `A-2-3-4-5` is the best
+    /// hand. Pairs still count against a hand.
+    AceToFive,
+    /// Deuce-to-seven lowball, as in Triple Draw: the hand which would lose
+    /// at high-hand poker wins. Aces are always high, so `A-2-3-4-5` is an
+    /// ace-high hand rather than a straight, and straights and flushes count
+    /// against a hand. `7-5-4-3-2` is the best hand.
+    DeuceToSeven,
+    /// Short-deck (6+) poker, played without the twos to fives. An ace can
+    /// play low in the straight `A-6-7-8-9`, and a flush beats a full house.
+    /// Some rule sets also rank three of a kind above a straight.
+    ShortDeck { trips_beat_straight: bool },
+}
+
+impl RankingRules {
+    /// Evaluates a hand written as five whitespace separated cards under
+    /// these rules.
+    pub fn evaluate(self, hand: &str) -> Result<EvaluatedHand, PokerError> {
+        let cards = parse_hand(hand, 5..=5)?;
+        for ((offset, token), card) in tokenize(hand).zip(&cards) {
+            self.check_rank(*card, offset, token)?;
+        }
+        Ok(evaluate_five_with(&cards, self))
+    }
+
+    /// Evaluates a hand of exactly five distinct cards under these rules.
+    ///
+    /// Errors use the position of the offending card in `cards` as their
+    /// offset.
+    pub fn evaluate_cards(self, cards: &[Card]) -> Result<EvaluatedHand, PokerError> {
+        self.check_cards(cards)?;
+        check_card_count(cards.len(), 5..=5, || join(cards))?;
+        Ok(evaluate_five_with(cards, self))
+    }
+
+    /// Picks the best five-card hand under these rules from five to seven
+    /// distinct cards, such as the seven cards of a Razz or stud hand.
+    ///
+    /// When several combinations make equally strong hands the first one
+    /// found is returned. Errors use the position of the offending card in
+    /// `cards` as their offset.
+    pub fn best_hand(self, cards: &[Card]) -> Result<BestHand, PokerError> {
+        self.check_cards(cards)?;
+        check_card_count(cards.len(), 5..=7, || join(cards))?;
+        Ok(best_of(cards, self))
+    }
+
+    /// Evaluates a Texas Hold'em hand under these rules: two hole cards plus
+    /// a board of three to five cards, using any five of them.
+    ///
+    /// Errors use the position of the offending card in the hole cards
+    /// followed by the board as their offset.
+    pub fn evaluate_holdem(self, hole: &[Card], board: &[Card]) -> Result<BestHand, PokerError> {
+        let cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+        self.check_cards(&cards)?;
+        check_card_count(hole.len(), 2..=2, || join(hole))?;
+        check_card_count(board.len(), 3..=5, || join(board))?;
+        Ok(best_of(&cards, self))
+    }
+
+    /// Compares two hands evaluated under these rules, returning `Greater`
+    /// if `a` beats `b`.
+    pub fn compare(self, a: &EvaluatedHand, b: &EvaluatedHand) -> Ordering {
+        match self {
+            RankingRules::High => a.cmp(b),
+            RankingRules::ShortDeck {
+                trips_beat_straight,
+            } => {
+                let strength = |h: &EvaluatedHand| match h.hand_type() {
+                    HandType::Flush => 6,
+                    HandType::FullHouse => 5,
+                    HandType::ThreeOfAKind if trips_beat_straight => 4,
+                    HandType::Straight if trips_beat_straight => 3,
+                    hand_type => hand_type as u8,
+                };
+                strength(a)
+                    .cmp(&strength(b))
+                    .then_with(|| a.tie_breaker().cmp(b.tie_breaker()))
+            }
+            RankingRules::DeuceToSeven => b.cmp(a),
+            RankingRules::AceToFive => {
+                let values = |h: &EvaluatedHand| -> Vec<u8> {
+                    h.tie_breaker().iter().map(|r| ace_low_value(*r)).collect()
+                };
+                b.hand_type()
+                    .cmp(&a.hand_type())
+                    .then_with(|| values(b).cmp(&values(a)))
+            }
+        }
+    }
+
+    /// Value of a rank when ordering cards under these rules.
+    pub(crate) fn rank_value(self, rank: Rank) -> u8 {
+        match self {
+            RankingRules::AceToFive => ace_low_value(rank),
+            _ => rank.value(),
+        }
+    }
+
+    pub(crate) fn counts_straights_and_flushes(self) -> bool {
+        self != RankingRules::AceToFive
+    }
+
+    /// The top card of the straight an ace can make by playing low, if it
+    /// can.
+    pub(crate) fn wheel_top(self) -> Option<Rank> {
+        match self {
+            RankingRules::High => Some(Rank::Five),
+            RankingRules::ShortDeck { .. } => Some(Rank::Nine),
+            RankingRules::AceToFive | RankingRules::DeuceToSeven => None,
+        }
+    }
+
+    /// Checks that the cards are distinct and all belong to the deck these
+    /// rules are played with.
+    fn check_cards(self, cards: &[Card]) -> Result<(), PokerError> {
+        check_distinct(cards)?;
+        for (i, card) in cards.iter().enumerate() {
+            self.check_rank(*card, i, &card.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn check_rank(self, card: Card, offset: usize, token: &str) -> Result<(), PokerError> {
+        match self {
+            RankingRules::ShortDeck { .. } if card.rank() < Rank::Six => {
+                Err(PokerError::InvalidRank {
+                    offset,
+                    token: token.to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{ranked_groups, Card, EvaluatedHand, PokerError, RankingRules};
+
+/// What one player put into the pot over a hand, and how they finished it.
+///
+/// Players are given to [`settle`] in seat order around the table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Contribution {
+    /// Every chip the player put in, across all betting rounds.
+    pub chips: u64,
+    /// Whether the player folded. A folded player's chips stay in the pots,
+    /// but they cannot win any of them.
+    pub folded: bool,
+    /// The five cards the player shows down, if they reached a showdown. A
+    /// player who is alone in a pot wins it without showing.
+    pub hand: Option<Vec<Card>>,
+}
+
+/// Who gets the chips left over when a pot does not split evenly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OddChip {
+    /// One chip each to the tied winners in seat order, starting with the
+    /// first seat to the left of the button, as in flop games.
+    LeftOfButton { button: usize },
+    /// One chip each to the tied winners in order of the lowest card in
+    /// their hands, comparing ranks and then suits (clubs, diamonds, hearts,
+    /// spades).
+    LowestCard,
+}
+
+/// One pot: the main pot or a side pot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pot {
+    amount: u64,
+    eligible: Vec<usize>,
+    winners: Vec<usize>,
+}
+
+impl Pot {
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// The seats of the players who can win this pot.
+    pub fn eligible(&self) -> &[usize] {
+        &self.eligible
+    }
+
+    /// The seats of the players who won this pot, more than one if they
+    /// split it.
+    pub fn winners(&self) -> &[usize] {
+        &self.winners
+    }
+}
+
+/// How the chips of a finished hand are shared out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Settlement {
+    pots: Vec<Pot>,
+    payouts: Vec<u64>,
+}
+
+impl Settlement {
+    /// The main pot followed by the side pots, in the order they were made.
+    pub fn pots(&self) -> &[Pot] {
+        &self.pots
+    }
+
+    /// The chips each player wins, by seat. These add up to every chip put
+    /// in.
+    pub fn payouts(&self) -> &[u64] {
+        &self.payouts
+    }
+
+    /// Hands chips no one else matched back to `seat`, as a pot of their
+    /// own, which is how [`settle`] treats them for a player who shows down.
+    pub(crate) fn return_uncalled(&mut self, seat: usize, amount: u64) {
+        self.payouts[seat] += amount;
+        self.pots.push(Pot {
+            amount,
+            eligible: vec![seat],
+            winners: vec![seat],
+        });
+    }
+}
+
+/// Builds the main and side pots of a finished hand and awards each one.
+///
+/// Each all-in amount caps a pot that only the players who put in at least
+/// that much can win. Each pot goes to the best hand among the players
+/// eligible for it under `rules`, exactly as
+/// [`winning_hands_with`](crate::winning_hands_with) would pick it; tied
+/// winners split it in whole chips, with any odd chips handed out by
+/// `odd_chip`.
+///
+/// A malformed hand gives the error [`RankingRules::evaluate_cards`] would.
+/// A player who must show down but has no hand gives
+/// [`EmptyHand`](PokerError::EmptyHand) with their seat as its offset. If
+/// every player has folded there is no one to award the chips to, which is
+/// an `EmptyHand` error at seat 0.
+pub fn settle(
+    players: &[Contribution],
+    rules: RankingRules,
+    odd_chip: OddChip,
+) -> Result<Settlement, PokerError> {
+    let hands = players
+        .iter()
+        .map(|player| match &player.hand {
+            Some(cards) if !player.folded => rules.evaluate_cards(cards).map(Some),
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<Option<EvaluatedHand>>, PokerError>>()?;
+
+    let mut caps: Vec<u64> = players
+        .iter()
+        .filter(|p| !p.folded)
+        .map(|p| p.chips)
+        .collect();
+    caps.sort_unstable();
+    caps.dedup();
+    let Some(&top) = caps.last() else {
+        return Err(no_hand(0));
+    };
+
+    let mut pots = Vec::new();
+    let mut payouts = vec![0; players.len()];
+    let mut previous = 0;
+    for cap in caps {
+        let mut amount: u64 = players
+            .iter()
+            .map(|p| p.chips.min(cap) - p.chips.min(previous))
+            .sum();
+        if cap == top {
+            // chips folded players put in above every live player's total
+            amount += players
+                .iter()
+                .map(|p| p.chips.saturating_sub(top))
+                .sum::<u64>();
+        }
+        previous = cap;
+        if amount == 0 {
+            continue;
+        }
+
+        let eligible: Vec<usize> = (0..players.len())
+            .filter(|seat| !players[*seat].folded && players[*seat].chips >= cap)
+            .collect();
+        let winners = if eligible.len() == 1 {
+            eligible.clone()
+        } else {
+            let contenders = eligible
+                .iter()
+                .map(|seat| Ok((hands[*seat].clone().ok_or_else(|| no_hand(*seat))?, *seat)))
+                .collect::<Result<Vec<(EvaluatedHand, usize)>, PokerError>>()?;
+            ranked_groups(contenders, |a, b| rules.compare(a, b))
+                .into_iter()
+                .next()
+                .unwrap_or_default()
+        };
+
+        let share = amount / winners.len() as u64;
+        let odd = (amount % winners.len() as u64) as usize;
+        for seat in &winners {
+            payouts[*seat] += share;
+        }
+        for seat in odd_chip_order(&winners, players, odd_chip)
+            .into_iter()
+            .take(odd)
+        {
+            payouts[seat] += 1;
+        }
+        pots.push(Pot {
+            amount,
+            eligible,
+            winners,
+        });
+    }
+    Ok(Settlement { pots, payouts })
+}
+
+/// The winners in the order they receive odd chips.
+fn odd_chip_order(winners: &[usize], players: &[Contribution], odd_chip: OddChip) -> Vec<usize> {
+    let mut order = winners.to_vec();
+    match odd_chip {
+        OddChip::LeftOfButton { button } => {
+            let seats = players.len();
+            order.sort_by_key(|seat| (seat + seats - button % seats - 1) % seats);
+        }
+        OddChip::LowestCard => {
+            order.sort_by_key(|seat| {
+                players[*seat]
+                    .hand
+                    .as_ref()
+                    .and_then(|cards| cards.iter().min().copied())
+            });
+        }
+    }
+    order
+}
+
+fn no_hand(seat: usize) -> PokerError {
+    PokerError::EmptyHand {
+        offset: seat,
+        token: String::new(),
+    }
+}
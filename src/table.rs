@@ -0,0 +1,265 @@
+use crate::holdem::best_of;
+use crate::{
+    Action, ActionError, Betting, Blinds, Card, Deck, OddChip, RankingRules, Settlement, Street,
+};
+
+/// A no-limit Texas Hold'em table: the players' stacks, the button and the
+/// blinds, carried from one hand to the next.
+///
+/// [`play`](Table::play) runs a whole hand, from the blinds to the payout.
+/// Everything that happens in it follows from the seed that shuffles the
+/// deck and the actions the players choose, so a hand can be replayed
+/// exactly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table {
+    stacks: Vec<u64>,
+    button: usize,
+    blinds: Blinds,
+}
+
+impl Table {
+    /// Seats players with the given stacks, the button at seat `button`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 22 seats, more than a deck can deal
+    /// hands and a board to, or the button is not one of them.
+    pub fn new(stacks: &[u64], button: usize, blinds: Blinds) -> Table {
+        assert!(stacks.len() <= 22, "a table has at most 22 seats");
+        assert!(button < stacks.len(), "the button must be at a seat");
+        Table {
+            stacks: stacks.to_vec(),
+            button,
+            blinds,
+        }
+    }
+
+    /// The chips each seat has between hands.
+    pub fn stacks(&self) -> &[u64] {
+        &self.stacks
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn blinds(&self) -> Blinds {
+        self.blinds
+    }
+
+    /// Shuffles a deck with `seed`, takes the antes and blinds and deals the
+    /// hole cards, leaving the first player to act. Seats with no chips are
+    /// left out. The table itself is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two seats have chips.
+    pub fn deal(&self, seed: u64) -> TableHand {
+        let seats: Vec<usize> = (0..self.stacks.len())
+            .filter(|seat| self.stacks[*seat] > 0)
+            .collect();
+        assert!(seats.len() >= 2, "a hand needs at least two players");
+        // a button on an empty seat passes to the next player
+        let button = seats
+            .iter()
+            .position(|seat| *seat >= self.button)
+            .unwrap_or(0);
+        let stacks: Vec<u64> = seats.iter().map(|seat| self.stacks[*seat]).collect();
+
+        let mut deck = Deck::new();
+        deck.shuffle(seed);
+        // one card at a time round the table, starting left of the button
+        let mut hole = vec![Vec::with_capacity(2); seats.len()];
+        for _ in 0..2 {
+            for i in 1..=seats.len() {
+                let player = (button + i) % seats.len();
+                hole[player].extend(deck.deal_one());
+            }
+        }
+
+        let mut hand = TableHand {
+            betting: Betting::new(&stacks, button, self.blinds),
+            blinds: self.blinds,
+            seats,
+            deck,
+            hole: hole.into_iter().map(|cards| [cards[0], cards[1]]).collect(),
+            board: Vec::new(),
+            actions: Vec::new(),
+        };
+        hand.advance();
+        hand
+    }
+
+    /// Plays a hand dealt from `seed` to the end, asking `decide` for the
+    /// action of each player in turn, then pays out the pots and passes the
+    /// button to the next seat with chips. Returns the finished hand.
+    ///
+    /// If `decide` chooses an action that is not allowed the hand is
+    /// abandoned with that error, and the table is left as it was.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two seats have chips.
+    pub fn play<F>(&mut self, seed: u64, mut decide: F) -> Result<TableHand, ActionError>
+    where
+        F: FnMut(&TableHand) -> Action,
+    {
+        let mut hand = self.deal(seed);
+        while hand.to_act().is_some() {
+            let action = decide(&hand);
+            hand.act(action)?;
+        }
+
+        let settlement = hand.settlement().expect("the hand is over");
+        for (player, seat) in hand.seats.iter().enumerate() {
+            self.stacks[*seat] = hand.betting.stacks()[player] + settlement.payouts()[player];
+        }
+        let button = hand.seats[hand.betting.button()];
+        let seats = self.stacks.len();
+        self.button = (1..=seats)
+            .map(|i| (button + i) % seats)
+            .find(|seat| self.stacks[*seat] > 0)
+            .unwrap_or(button);
+        Ok(hand)
+    }
+}
+
+/// One hand in progress at a [`Table`], or finished.
+///
+/// The players dealt in are numbered from 0 in seat order, as in
+/// [`Betting`]; [`seats`](TableHand::seats) gives the table seat of each.
+/// The flop, turn and river are dealt, each after a burn card, as soon as
+/// the betting before them is complete, and all at once when no more
+/// betting is possible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableHand {
+    seats: Vec<usize>,
+    deck: Deck,
+    hole: Vec<[Card; 2]>,
+    board: Vec<Card>,
+    betting: Betting,
+    blinds: Blinds,
+    actions: Vec<(Street, usize, Action)>,
+}
+
+impl TableHand {
+    /// The table seat of each player in the hand.
+    pub fn seats(&self) -> &[usize] {
+        &self.seats
+    }
+
+    /// Each player's two hole cards.
+    pub fn hole_cards(&self) -> &[[Card; 2]] {
+        &self.hole
+    }
+
+    /// The community cards dealt so far.
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// The cards burned before each street dealt so far.
+    pub fn burned(&self) -> &[Card] {
+        self.deck.burned()
+    }
+
+    /// The betting so far: stacks, bets, the pot and the legal actions.
+    pub fn betting(&self) -> &Betting {
+        &self.betting
+    }
+
+    pub fn blinds(&self) -> Blinds {
+        self.blinds
+    }
+
+    pub fn street(&self) -> Street {
+        self.betting.street()
+    }
+
+    /// The player to act, or `None` once the hand is over.
+    pub fn to_act(&self) -> Option<usize> {
+        self.betting.to_act()
+    }
+
+    /// Every action taken, in order, with the street and the player.
+    pub fn actions(&self) -> &[(Street, usize, Action)] {
+        &self.actions
+    }
+
+    /// Applies the action of the player to act, then deals the next street
+    /// if the betting round is complete.
+    pub fn act(&mut self, action: Action) -> Result<(), ActionError> {
+        let player = self.to_act().ok_or(ActionError::NoPlayerToAct)?;
+        let street = self.street();
+        self.betting.act(action)?;
+        self.actions.push((street, player, action));
+        self.advance();
+        Ok(())
+    }
+
+    /// Whether the hand is over, by everyone else folding or by reaching
+    /// the showdown.
+    pub fn is_over(&self) -> bool {
+        self.betting.is_hand_over()
+    }
+
+    /// The best five cards each player still in the hand shows down, once
+    /// the board is complete. `None` for players who folded, and for
+    /// everyone when the hand was won without a showdown.
+    pub fn showdown_hands(&self) -> Vec<Option<[Card; 5]>> {
+        let showdown = self.street() == Street::Showdown;
+        (0..self.seats.len())
+            .map(|player| {
+                if !showdown || self.betting.folded()[player] {
+                    return None;
+                }
+                let cards: Vec<Card> = self.hole[player]
+                    .iter()
+                    .chain(&self.board)
+                    .copied()
+                    .collect();
+                Some(*best_of(&cards, RankingRules::High).cards())
+            })
+            .collect()
+    }
+
+    /// How the pots are shared out, once the hand is over. Odd chips go to
+    /// the first winners left of the button.
+    pub fn settlement(&self) -> Option<Settlement> {
+        if !self.is_over() {
+            return None;
+        }
+        let hands: Vec<Option<Vec<Card>>> = self
+            .showdown_hands()
+            .into_iter()
+            .map(|cards| cards.map(|cards| cards.to_vec()))
+            .collect();
+        let odd_chip = OddChip::LeftOfButton {
+            button: self.betting.button(),
+        };
+        let settlement = self
+            .betting
+            .showdown(&hands, RankingRules::High, odd_chip)
+            .expect("every player at the showdown has a hand");
+        Some(settlement)
+    }
+
+    /// Moves on through the streets while no one is to act, dealing the
+    /// board as it goes, until someone must act or the hand is over.
+    fn advance(&mut self) {
+        while self.to_act().is_none() && !self.betting.is_hand_over() {
+            let street = self.betting.next_street().expect("the round is complete");
+            let cards = match street {
+                Street::Flop => 3,
+                Street::Turn | Street::River => 1,
+                Street::Preflop | Street::Showdown => continue,
+            };
+            self.deck.burn();
+            self.board.extend(
+                self.deck
+                    .deal(cards)
+                    .expect("the deck has cards for the board"),
+            );
+        }
+    }
+}
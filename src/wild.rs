@@ -0,0 +1,183 @@
+use crate::card::parse_playing_card;
+use crate::hand::{check_card_count, evaluate_five, tokenize};
+use crate::{best_group, Card, EvaluatedHand, PlayingCard, PokerError, Rank, Suit};
+
+/// Which cards are wild. Jokers are always wild; any number of ranks can be
+/// made wild as well, e.g. deuces wild.
+///
+/// A wild card stands for whichever card gives the best hand, so five of a
+/// kind is possible. Wild cards do not duplicate a card already in the hand
+/// to make a flush or straight flush, but may stand for any rank otherwise.
+/// Hands are ranked by the standard high-hand rules.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct WildCards {
+    ranks: Vec<Rank>,
+}
+
+impl WildCards {
+    /// Only jokers are wild.
+    pub fn jokers() -> WildCards {
+        WildCards::default()
+    }
+
+    /// Jokers and every card of the given ranks are wild, e.g. `&[Rank::Two]`
+    /// for deuces wild.
+    pub fn with_ranks(ranks: &[Rank]) -> WildCards {
+        WildCards {
+            ranks: ranks.to_vec(),
+        }
+    }
+
+    pub fn is_wild(&self, card: PlayingCard) -> bool {
+        match card {
+            PlayingCard::Joker => true,
+            PlayingCard::Card(card) => self.ranks.contains(&card.rank()),
+        }
+    }
+
+    /// Evaluates a hand written as five whitespace separated cards, any of
+    /// which may be jokers (`JK` or `XX`).
+    pub fn evaluate(&self, hand: &str) -> Result<EvaluatedHand, PokerError> {
+        let mut cards = Vec::with_capacity(5);
+        for (offset, token) in tokenize(hand) {
+            let card = parse_playing_card(token, offset)?;
+            if card != PlayingCard::Joker && cards.contains(&card) {
+                return Err(PokerError::DuplicateCard {
+                    offset,
+                    token: token.to_string(),
+                });
+            }
+            cards.push(card);
+        }
+        check_card_count(cards.len(), 5..=5, || hand.to_string())?;
+        Ok(self.resolve(&cards))
+    }
+
+    /// Evaluates a hand of exactly five cards, any of which may be jokers.
+    ///
+    /// Errors use the position of the offending card in `cards` as their
+    /// offset.
+    pub fn evaluate_cards(&self, cards: &[PlayingCard]) -> Result<EvaluatedHand, PokerError> {
+        for (i, card) in cards.iter().enumerate() {
+            if *card != PlayingCard::Joker && cards[..i].contains(card) {
+                return Err(PokerError::DuplicateCard {
+                    offset: i,
+                    token: card.to_string(),
+                });
+            }
+        }
+        check_card_count(cards.len(), 5..=5, || {
+            cards
+                .iter()
+                .map(PlayingCard::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })?;
+        Ok(self.resolve(cards))
+    }
+
+    /// Finds the best hand the wild cards can make, trying every combination
+    /// of ranks for them.
+    fn resolve(&self, cards: &[PlayingCard]) -> EvaluatedHand {
+        let mut natural: Vec<Card> = Vec::with_capacity(5);
+        for card in cards {
+            match card {
+                PlayingCard::Card(card) if !self.is_wild(PlayingCard::Card(*card)) => {
+                    natural.push(*card)
+                }
+                _ => {}
+            }
+        }
+        let wild_count = cards.len() - natural.len();
+        if wild_count == 0 {
+            return evaluate_five(&natural);
+        }
+
+        // a flush is only possible if every natural card shares a suit
+        let flush_suit = match natural.first() {
+            None => Some(Suit::Spade),
+            Some(first) => Some(first.suit()).filter(|s| natural.iter().all(|c| c.suit() == *s)),
+        };
+        // any suit other than the flush suit keeps wild cards out of a flush
+        let off_suit = match flush_suit {
+            Some(Suit::Club) => Suit::Diamond,
+            _ => Suit::Club,
+        };
+
+        let mut best: Option<EvaluatedHand> = None;
+        for_each_rank_multiset(wild_count, |ranks| {
+            let mut hand = natural.clone();
+            hand.extend(ranks.iter().map(|rank| Card::new(*rank, off_suit)));
+            keep_better(&mut best, evaluate_five(&hand));
+
+            if let Some(suit) = flush_suit {
+                let mut hand = natural.clone();
+                hand.extend(ranks.iter().map(|rank| Card::new(*rank, suit)));
+                let distinct = hand
+                    .iter()
+                    .enumerate()
+                    .all(|(i, card)| !hand[..i].contains(card));
+                if distinct {
+                    keep_better(&mut best, evaluate_five(&hand));
+                }
+            }
+        });
+        best.expect("there is at least one wild card")
+    }
+}
+
+/// Like [`winning_hands`](crate::winning_hands), but with wild cards and
+/// jokers.
+///
+/// # Panics
+///
+/// Panics if any of the hands is malformed. Use [`try_winning_hands_wild`]
+/// to get the error back instead.
+pub fn winning_hands_wild<'a>(hands: &[&'a str], wild: &WildCards) -> Option<Vec<&'a str>> {
+    match try_winning_hands_wild(hands, wild) {
+        Ok(winners) => winners,
+        Err(e) => panic!("Malformed hand: {}", e),
+    }
+}
+
+/// Fallible version of [`winning_hands_wild`].
+pub fn try_winning_hands_wild<'a>(
+    hands: &[&'a str],
+    wild: &WildCards,
+) -> Result<Option<Vec<&'a str>>, PokerError> {
+    let evaluated_hands = hands
+        .iter()
+        .map(|hand| Ok((wild.evaluate(hand)?, *hand)))
+        .collect::<Result<Vec<(EvaluatedHand, &'a str)>, PokerError>>()?;
+    let winning_strings = best_group(evaluated_hands, |a, b| a.cmp(b));
+
+    if winning_strings.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(winning_strings))
+    }
+}
+
+fn keep_better(best: &mut Option<EvaluatedHand>, hand: EvaluatedHand) {
+    if best.as_ref().is_none_or(|b| hand > *b) {
+        *best = Some(hand);
+    }
+}
+
+/// Calls `f` with every multiset of `k` ranks, as the order wild cards are
+/// assigned in does not matter.
+fn for_each_rank_multiset(k: usize, mut f: impl FnMut(&[Rank])) {
+    fn recurse(start: usize, k: usize, chosen: &mut Vec<Rank>, f: &mut impl FnMut(&[Rank])) {
+        if chosen.len() == k {
+            f(chosen);
+            return;
+        }
+        for i in start..Rank::ALL.len() {
+            chosen.push(Rank::ALL[i]);
+            recurse(i, k, chosen, f);
+            chosen.pop();
+        }
+    }
+
+    recurse(0, k, &mut Vec::with_capacity(k), &mut f);
+}
@@ -0,0 +1,315 @@
+use poker::{Action, ActionError, Betting, Blinds, Card, OddChip, RankingRules, Street};
+
+const BLINDS: Blinds = Blinds {
+    small: 1,
+    big: 2,
+    ante: 0,
+};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+fn act_all(betting: &mut Betting, actions: &[Action]) {
+    for action in actions {
+        betting.act(*action).unwrap();
+    }
+}
+
+#[test]
+fn test_blinds_and_antes_are_posted() {
+    let betting = Betting::new(
+        &[100, 100, 100, 100],
+        0,
+        Blinds {
+            small: 5,
+            big: 10,
+            ante: 1,
+        },
+    );
+    assert_eq!(betting.street(), Street::Preflop);
+    assert_eq!(betting.bets(), &[0, 5, 10, 0]);
+    assert_eq!(betting.stacks(), &[99, 94, 89, 99]);
+    assert_eq!(betting.contributed(), &[1, 6, 11, 1]);
+    assert_eq!(betting.pot(), 19);
+    assert_eq!(betting.current_bet(), 10);
+    // under the gun, left of the big blind, acts first
+    assert_eq!(betting.to_act(), Some(3));
+}
+
+#[test]
+fn test_short_blind_is_all_in() {
+    let betting = Betting::new(
+        &[100, 1, 100],
+        0,
+        Blinds {
+            small: 5,
+            big: 10,
+            ante: 0,
+        },
+    );
+    assert_eq!(betting.stacks(), &[100, 0, 90]);
+    assert_eq!(betting.bets(), &[0, 1, 10]);
+}
+
+#[test]
+fn test_heads_up_order() {
+    let mut betting = Betting::new(&[100, 100], 1, BLINDS);
+    // the button posts the small blind and acts first before the flop
+    assert_eq!(betting.bets(), &[2, 1]);
+    assert_eq!(betting.to_act(), Some(1));
+    act_all(&mut betting, &[Action::Call, Action::Check]);
+    assert!(betting.is_round_complete());
+    assert_eq!(betting.next_street(), Ok(Street::Flop));
+    // and last after it
+    assert_eq!(betting.to_act(), Some(0));
+}
+
+#[test]
+fn test_big_blind_gets_the_option() {
+    let mut betting = Betting::new(&[100, 100, 100], 0, BLINDS);
+    act_all(&mut betting, &[Action::Call, Action::Call]);
+    assert_eq!(betting.to_act(), Some(2));
+    let legal = betting.legal_actions().unwrap();
+    assert!(legal.can_check());
+    assert!(!legal.can_fold());
+    assert_eq!(legal.call_amount(), None);
+    assert_eq!(legal.raise_range(), Some(4..=100));
+    assert_eq!(legal.bet_range(), None);
+    betting.act(Action::Check).unwrap();
+    assert!(betting.is_round_complete());
+    assert_eq!(betting.pot(), 6);
+}
+
+#[test]
+fn test_minimum_raise_follows_the_last_raise() {
+    let mut betting = Betting::new(&[100, 100, 100], 0, BLINDS);
+    let legal = betting.legal_actions().unwrap();
+    assert_eq!(legal.call_amount(), Some(2));
+    assert_eq!(legal.raise_range(), Some(4..=100));
+
+    // a raise by 4 to 6 means the next raise must be to at least 10
+    betting.act(Action::Raise(6)).unwrap();
+    let legal = betting.legal_actions().unwrap();
+    assert_eq!(legal.call_amount(), Some(5));
+    assert_eq!(legal.raise_range(), Some(10..=100));
+    let err = betting.act(Action::Raise(8)).unwrap_err();
+    assert_eq!(
+        err,
+        ActionError::InvalidAmount {
+            action: Action::Raise(8),
+            min: 10,
+            max: 100
+        }
+    );
+    assert_eq!(err.to_string(), "Raise(8) must be between 10 and 100 chips");
+    betting.act(Action::Raise(20)).unwrap();
+    assert_eq!(
+        betting.legal_actions().unwrap().raise_range(),
+        Some(34..=100)
+    );
+}
+
+#[test]
+fn test_bets_after_the_flop() {
+    let mut betting = Betting::new(&[100, 100, 100], 0, BLINDS);
+    act_all(&mut betting, &[Action::Call, Action::Call, Action::Check]);
+    assert_eq!(betting.next_street(), Ok(Street::Flop));
+    assert_eq!(betting.bets(), &[0, 0, 0]);
+    // the small blind acts first after the flop
+    assert_eq!(betting.to_act(), Some(1));
+    let legal = betting.legal_actions().unwrap();
+    assert_eq!(legal.bet_range(), Some(2..=98));
+    assert_eq!(legal.raise_range(), None);
+    assert_eq!(
+        betting.act(Action::Raise(10)),
+        Err(ActionError::Illegal(Action::Raise(10)))
+    );
+    assert_eq!(
+        betting.act(Action::Fold),
+        Err(ActionError::Illegal(Action::Fold))
+    );
+
+    act_all(&mut betting, &[Action::Bet(10), Action::Fold, Action::Call]);
+    assert!(betting.is_round_complete());
+    assert_eq!(betting.folded(), &[false, false, true]);
+    assert_eq!(betting.pot(), 26);
+}
+
+#[test]
+fn test_short_all_in_does_not_reopen_betting() {
+    let mut betting = Betting::new(&[25, 200, 200, 200], 0, BLINDS);
+    act_all(
+        &mut betting,
+        &[Action::Call, Action::Call, Action::Call, Action::Check],
+    );
+    betting.next_street().unwrap();
+    // seat 1 bets 20 and is called, then seat 0 is all in for 23: a raise of 3
+    act_all(&mut betting, &[Action::Bet(20), Action::Call, Action::Call]);
+    assert_eq!(betting.to_act(), Some(0));
+    let legal = betting.legal_actions().unwrap();
+    assert_eq!(legal.raise_range(), Some(23..=23));
+    betting.act(Action::AllIn).unwrap();
+    assert_eq!(betting.current_bet(), 23);
+
+    // seat 1 has acted already, so can only call or fold
+    assert_eq!(betting.to_act(), Some(1));
+    let legal = betting.legal_actions().unwrap();
+    assert_eq!(legal.call_amount(), Some(3));
+    assert_eq!(legal.raise_range(), None);
+    assert_eq!(
+        betting.act(Action::AllIn),
+        Err(ActionError::Illegal(Action::AllIn))
+    );
+    act_all(&mut betting, &[Action::Call, Action::Call, Action::Call]);
+    assert!(betting.is_round_complete());
+}
+
+#[test]
+fn test_short_all_ins_adding_up_to_a_full_raise_reopen_betting() {
+    let blinds = Blinds {
+        small: 5,
+        big: 10,
+        ante: 0,
+    };
+    let mut betting = Betting::new(&[1000, 150, 210, 1000], 3, blinds);
+    act_all(
+        &mut betting,
+        &[Action::Call, Action::Call, Action::Call, Action::Check],
+    );
+    betting.next_street().unwrap();
+    // seat 0 bets 100, then all-ins to 140 and 200 each raise by less
+    act_all(
+        &mut betting,
+        &[Action::Bet(100), Action::AllIn, Action::AllIn, Action::Call],
+    );
+    assert_eq!(betting.current_bet(), 200);
+
+    // but together they raise seat 0 by a full 100
+    assert_eq!(betting.to_act(), Some(0));
+    let legal = betting.legal_actions().unwrap();
+    assert_eq!(legal.call_amount(), Some(100));
+    assert_eq!(legal.raise_range(), Some(300..=990));
+    betting.act(Action::Raise(300)).unwrap();
+
+    // seat 3 faces a full raise of its own
+    assert_eq!(betting.to_act(), Some(3));
+    assert_eq!(
+        betting.legal_actions().unwrap().raise_range(),
+        Some(400..=990)
+    );
+}
+
+#[test]
+fn test_full_raise_reopens_betting() {
+    let mut betting = Betting::new(&[200, 200, 200], 0, BLINDS);
+    act_all(&mut betting, &[Action::Raise(6)]);
+    act_all(&mut betting, &[Action::Raise(10)]);
+    // the big blind's raise of 4 is a full raise, so seat 0 can raise again
+    act_all(&mut betting, &[Action::Call]);
+    assert_eq!(betting.to_act(), Some(0));
+    assert_eq!(
+        betting.legal_actions().unwrap().raise_range(),
+        Some(14..=200)
+    );
+}
+
+#[test]
+fn test_round_must_be_complete_to_move_on() {
+    let mut betting = Betting::new(&[100, 100], 0, BLINDS);
+    assert_eq!(betting.next_street(), Err(ActionError::RoundNotComplete));
+    act_all(&mut betting, &[Action::Call, Action::Check]);
+    for street in [Street::Flop, Street::Turn, Street::River] {
+        assert_eq!(betting.next_street(), Ok(street));
+        act_all(&mut betting, &[Action::Check, Action::Check]);
+    }
+    assert_eq!(betting.next_street(), Ok(Street::Showdown));
+    assert!(betting.is_hand_over());
+    assert_eq!(betting.act(Action::Check), Err(ActionError::NoPlayerToAct));
+}
+
+#[test]
+fn test_all_in_players_skip_later_streets() {
+    let mut betting = Betting::new(&[50, 100], 0, BLINDS);
+    act_all(&mut betting, &[Action::AllIn, Action::Call]);
+    assert!(betting.is_round_complete());
+    assert_eq!(betting.stacks(), &[0, 50]);
+    assert_eq!(betting.next_street(), Ok(Street::Flop));
+    assert_eq!(betting.to_act(), None);
+}
+
+#[test]
+fn test_fold_ends_the_hand() {
+    let mut betting = Betting::new(&[100, 100, 100], 0, BLINDS);
+    act_all(
+        &mut betting,
+        &[Action::Raise(10), Action::Fold, Action::Fold],
+    );
+    assert!(betting.is_hand_over());
+    let settlement = betting
+        .showdown(
+            &[None, None, None],
+            RankingRules::High,
+            OddChip::LeftOfButton { button: 0 },
+        )
+        .unwrap();
+    assert_eq!(settlement.payouts(), &[13, 0, 0]);
+}
+
+#[test]
+fn test_showdown_uses_hand_comparison() {
+    let mut betting = Betting::new(&[50, 100, 100], 0, BLINDS);
+    act_all(&mut betting, &[Action::AllIn, Action::Call, Action::Call]);
+    while betting.street() != Street::Showdown {
+        while betting.to_act().is_some() {
+            betting.act(Action::Check).unwrap();
+        }
+        betting.next_street().unwrap();
+    }
+    let hands = [
+        Some(cards("AS AH AD 4C 2S")),
+        Some(cards("KS KH 7C 4D 2D")),
+        Some(cards("QS QH 7D 4H 2H")),
+    ];
+    let settlement = betting
+        .showdown(
+            &hands,
+            RankingRules::High,
+            OddChip::LeftOfButton { button: 0 },
+        )
+        .unwrap();
+    assert_eq!(settlement.payouts(), &[150, 0, 0]);
+}
+
+#[test]
+fn test_minimum_bet() {
+    let antes = Blinds {
+        small: 0,
+        big: 0,
+        ante: 5,
+    };
+    // with no big blind a bet is still at least one chip
+    let mut betting = Betting::new(&[100, 100], 0, antes);
+    let legal = betting.legal_actions().unwrap();
+    assert!(legal.can_check());
+    assert_eq!(legal.bet_range(), Some(1..=95));
+    assert_eq!(
+        betting.act(Action::Bet(0)),
+        Err(ActionError::InvalidAmount {
+            action: Action::Bet(0),
+            min: 1,
+            max: 95
+        })
+    );
+
+    let mut betting = Betting::with_min_bet(&[100, 100], 0, antes, 10);
+    assert_eq!(betting.legal_actions().unwrap().bet_range(), Some(10..=95));
+    act_all(&mut betting, &[Action::Bet(10)]);
+    assert_eq!(
+        betting.legal_actions().unwrap().raise_range(),
+        Some(20..=95)
+    );
+    act_all(&mut betting, &[Action::Call]);
+    betting.next_street().unwrap();
+    assert_eq!(betting.legal_actions().unwrap().bet_range(), Some(10..=85));
+}
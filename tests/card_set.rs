@@ -0,0 +1,104 @@
+use poker::{best_hand, hand_rank, Card, CardSet, HandType, PokerError};
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+fn set(s: &str) -> CardSet {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_insert_contains_and_remove() {
+    let mut cards = CardSet::new();
+    assert!(cards.is_empty());
+    assert!(cards.insert(card("AS")));
+    assert!(!cards.insert(card("AS")));
+    assert!(cards.insert(card("2C")));
+    assert_eq!(cards.len(), 2);
+    assert!(cards.contains(card("AS")));
+    assert!(!cards.contains(card("AH")));
+    assert!(cards.remove(card("AS")));
+    assert!(!cards.remove(card("AS")));
+    assert_eq!(cards, CardSet::from(card("2C")));
+}
+
+#[test]
+fn test_bits_follow_card_index() {
+    assert_eq!(CardSet::from(card("2C")).bits(), 1);
+    assert_eq!(CardSet::from(card("AS")).bits(), 1 << 51);
+    assert_eq!(CardSet::full().len(), 52);
+    assert_eq!(CardSet::from_bits(u64::MAX), CardSet::full());
+}
+
+#[test]
+fn test_set_operations() {
+    let a = set("AS KS QS");
+    let b = set("QS JS");
+    assert_eq!(a | b, set("AS KS QS JS"));
+    assert_eq!(a & b, set("QS"));
+    assert_eq!(a - b, set("AS KS"));
+    assert_eq!(a.union(b), a | b);
+    assert_eq!(a.intersection(b), a & b);
+    assert_eq!(a.difference(b), a - b);
+    assert_eq!((!a).len(), 49);
+    assert!(!(!a).contains(card("KS")));
+    assert!(set("AS KS").is_subset(a));
+    assert!(a.is_disjoint(set("AH KH")));
+    assert!(!a.is_disjoint(b));
+}
+
+#[test]
+fn test_iterates_in_index_order() {
+    let cards = set("AS 2C 10H 2D");
+    assert_eq!(
+        cards.iter().collect::<Vec<_>>(),
+        vec![card("2C"), card("2D"), card("10H"), card("AS")]
+    );
+    assert_eq!(cards.iter().len(), 4);
+    assert_eq!(cards.to_vec().into_iter().collect::<CardSet>(), cards);
+    assert_eq!(CardSet::full().iter().count(), 52);
+}
+
+#[test]
+fn test_parse_and_format() {
+    assert_eq!(set("AS 2C 10H").to_string(), "2C 10H AS");
+    assert_eq!(set(""), CardSet::new());
+    assert_eq!(CardSet::new().to_string(), "");
+    assert_eq!(
+        "AS KS AS".parse::<CardSet>(),
+        Err(PokerError::DuplicateCard {
+            offset: 6,
+            token: "AS".to_string()
+        })
+    );
+    assert_eq!(
+        "AS 1S".parse::<CardSet>(),
+        Err(PokerError::InvalidRank {
+            offset: 3,
+            token: "1S".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_evaluates_sets() {
+    let hand = set("10S JS QS KS AS").evaluate().unwrap();
+    assert_eq!(hand.hand_type(), HandType::StraightFlush);
+    assert!(set("10S JS QS KS").evaluate().is_err());
+
+    let seven = set("AS AH 7D 7C 2S 3H 9D");
+    let best = seven.best_hand().unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::TwoPair);
+    assert_eq!(seven.hand_rank(), hand_rank(&seven.to_vec()));
+    assert_eq!(best.hand(), best_hand(&seven.to_vec()).unwrap().hand());
+}
+
+#[test]
+fn test_deck_arithmetic() {
+    let dead = set("AS KS 7D 2C");
+    let live = CardSet::full() - dead;
+    assert_eq!(live.len(), 48);
+    assert!(live.is_disjoint(dead));
+    assert_eq!(live | dead, CardSet::full());
+}
@@ -0,0 +1,74 @@
+use poker::{Card, PokerError, Rank, Suit};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+#[test]
+fn test_card_round_trips_through_strings() {
+    for index in 0..52 {
+        let card = Card::try_from(index).unwrap();
+        let text = card.to_string();
+        assert_eq!(text.parse::<Card>(), Ok(card));
+    }
+}
+
+#[test]
+fn test_card_notation() {
+    assert_eq!("10H".parse::<Card>(), Ok(Card::new(Rank::Ten, Suit::Heart)));
+    assert_eq!(Card::new(Rank::Ace, Suit::Spade).to_string(), "AS");
+    assert_eq!(Card::new(Rank::Two, Suit::Club).to_string(), "2C");
+}
+
+#[test]
+fn test_card_rejects_other_notation() {
+    for bad in &["TH", "1H", "11H", "010H", "ah", "A", ""] {
+        assert!(bad.parse::<Card>().is_err(), "{:?} should not parse", bad);
+    }
+    assert_eq!(
+        "AX".parse::<Card>(),
+        Err(PokerError::InvalidSuit {
+            offset: 0,
+            token: "AX".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_card_index_matches_ordering() {
+    let cards: Vec<Card> = (0..52).map(|i| Card::try_from(i).unwrap()).collect();
+    let mut sorted = cards.clone();
+    sorted.sort();
+    assert_eq!(cards, sorted);
+    assert!(cards
+        .iter()
+        .enumerate()
+        .all(|(i, c)| c.index() as usize == i));
+    assert_eq!(cards.iter().collect::<HashSet<_>>().len(), 52);
+    let err = Card::try_from(52).unwrap_err();
+    assert_eq!(
+        err,
+        PokerError::InvalidCardIndex {
+            offset: 0,
+            token: "52".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "card index 52 is not between 0 and 51");
+}
+
+#[test]
+fn test_rank_from_value() {
+    assert_eq!(Rank::try_from(14), Ok(Rank::Ace));
+    assert_eq!(Rank::try_from(2), Ok(Rank::Two));
+    assert!(Rank::try_from(1).is_err());
+    assert!(Rank::try_from(15).is_err());
+    assert!(Rank::ALL
+        .iter()
+        .all(|r| Rank::try_from(r.value()) == Ok(*r)));
+    assert!(Rank::Ace > Rank::King);
+}
+
+#[test]
+fn test_suit_from_index() {
+    assert_eq!(Suit::try_from(3), Ok(Suit::Spade));
+    assert!(Suit::try_from(4).is_err());
+    assert!(Suit::ALL.iter().all(|s| s.to_string().parse() == Ok(*s)));
+}
@@ -0,0 +1,105 @@
+use poker::{winning_hands, Card, CardSet, Deck, PlayingCard, Rank, WildCards};
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_new_deck_is_complete_and_ordered() {
+    let deck = Deck::new();
+    assert_eq!(deck.len(), 52);
+    assert_eq!(deck.cards()[0], card("2C"));
+    assert_eq!(deck.cards()[51], card("AS"));
+    assert_eq!(
+        deck.cards().iter().copied().collect::<CardSet>(),
+        CardSet::full()
+    );
+    assert_eq!(Deck::default(), deck);
+}
+
+#[test]
+fn test_shuffle_is_deterministic() {
+    let mut first = Deck::new();
+    let mut second = Deck::new();
+    first.shuffle(17);
+    second.shuffle(17);
+    assert_eq!(first, second);
+    assert_ne!(first, Deck::new());
+
+    let mut other = Deck::new();
+    other.shuffle(18);
+    assert_ne!(first, other);
+    assert_eq!(
+        first.cards().iter().copied().collect::<CardSet>(),
+        CardSet::full()
+    );
+}
+
+#[test]
+fn test_deal_and_burn() {
+    let mut deck = Deck::new();
+    deck.shuffle(3);
+    let top = deck.cards()[..3].to_vec();
+    assert_eq!(deck.deal(2), Some(top[..2].to_vec()));
+    assert_eq!(deck.burn(), Some(top[2]));
+    assert_eq!(deck.burned(), &top[2..]);
+    assert_eq!(deck.len(), 49);
+    assert_eq!(deck.deal(50), None);
+    assert_eq!(deck.len(), 49);
+    assert_eq!(deck.deal(49).map(|cards| cards.len()), Some(49));
+    assert!(deck.is_empty());
+    assert_eq!(deck.deal_one(), None);
+    assert_eq!(deck.burn(), None);
+}
+
+#[test]
+fn test_remove_known_cards() {
+    let mut deck = Deck::new();
+    assert!(deck.remove(card("AS")));
+    assert!(!deck.remove(card("AS")));
+    assert_eq!(deck.len(), 51);
+    assert!(!deck.cards().contains(&card("AS")));
+}
+
+#[test]
+fn test_short_deck() {
+    let deck = Deck::short();
+    assert_eq!(deck.len(), 36);
+    assert!(deck.cards().iter().all(|card| card.rank() >= Rank::Six));
+}
+
+#[test]
+fn test_joker_deck() {
+    let mut deck = Deck::with_jokers(2);
+    assert_eq!(deck.len(), 54);
+    assert!(deck.remove(PlayingCard::Joker));
+    assert!(deck.remove(PlayingCard::Joker));
+    assert!(!deck.remove(PlayingCard::Joker));
+
+    let mut deck = Deck::with_jokers(1);
+    deck.shuffle(5);
+    let hand: Vec<PlayingCard> = deck.deal(5).unwrap();
+    let text: Vec<String> = hand.iter().map(|card| card.to_string()).collect();
+    assert!(WildCards::jokers().evaluate(&text.join(" ")).is_ok());
+}
+
+#[test]
+fn test_dealt_hands_can_be_compared() {
+    let mut deck = Deck::new();
+    deck.shuffle(11);
+    let hands: Vec<String> = (0..4)
+        .map(|_| {
+            let cards: Vec<String> = deck
+                .deal(5)
+                .unwrap()
+                .iter()
+                .map(|c| c.to_string())
+                .collect();
+            cards.join(" ")
+        })
+        .collect();
+    let hands: Vec<&str> = hands.iter().map(String::as_str).collect();
+    let winners = winning_hands(&hands).unwrap();
+    assert!(!winners.is_empty());
+    assert!(winners.iter().all(|winner| hands.contains(winner)));
+}
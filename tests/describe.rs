@@ -0,0 +1,62 @@
+use poker::{evaluate, HandType, Rank, WildCards};
+
+fn describe(hand: &str) -> String {
+    evaluate(hand).unwrap().to_string()
+}
+
+#[test]
+fn test_rank_names() {
+    assert_eq!(Rank::Queen.name(), "Queen");
+    assert_eq!(Rank::Ten.name(), "Ten");
+    assert_eq!(Rank::Six.plural_name(), "Sixes");
+    assert_eq!(Rank::Ace.plural_name(), "Aces");
+    assert_eq!(HandType::ThreeOfAKind.to_string(), "Three of a kind");
+}
+
+#[test]
+fn test_describe_every_category() {
+    assert_eq!(describe("AS 9H 7D 4C 2S"), "High card, Ace");
+    assert_eq!(describe("AS AH 7D 4C 2S"), "One pair, Aces");
+    assert_eq!(
+        describe("JS JH 8D 8C QS"),
+        "Two pair, Jacks and Eights with a Queen kicker"
+    );
+    assert_eq!(describe("7S 7H 7D 4C 2S"), "Three of a kind, Sevens");
+    assert_eq!(describe("6S 7H 8D 9C 10S"), "Straight, Ten high");
+    assert_eq!(describe("AS 9S 7S 4S 2S"), "Flush, Ace high");
+    assert_eq!(
+        describe("KS KH KD 4C 4S"),
+        "Full house, Kings full of Fours"
+    );
+    assert_eq!(
+        describe("9S 9H 9D 9C JS"),
+        "Four of a kind, Nines with a Jack kicker"
+    );
+    assert_eq!(describe("5H 6H 7H 8H 9H"), "Straight flush, Nine high");
+    assert_eq!(describe("10S JS QS KS AS"), "Royal flush");
+    assert_eq!(
+        WildCards::jokers()
+            .evaluate("6S 6H 6D 6C JK")
+            .unwrap()
+            .to_string(),
+        "Five of a kind, Sixes"
+    );
+}
+
+#[test]
+fn test_describe_wheel() {
+    assert_eq!(describe("AS 2H 3D 4C 5S"), "Straight, Five high");
+    assert_eq!(describe("AH 2H 3H 4H 5H"), "Straight flush, Five high");
+}
+
+#[test]
+fn test_describe_uses_an_before_vowel_sounds() {
+    assert_eq!(
+        describe("JS JH 4D 4C AS"),
+        "Two pair, Jacks and Fours with an Ace kicker"
+    );
+    assert_eq!(
+        describe("9S 9H 9D 9C 8S"),
+        "Four of a kind, Nines with an Eight kicker"
+    );
+}
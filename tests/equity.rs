@@ -0,0 +1,308 @@
+use poker::{
+    evaluate_holdem, evaluate_omaha, exhaustive_equity, exhaustive_omaha_equity,
+    monte_carlo_equity, range_equity, Budget, Card, EvaluatedHand, PokerError, Range,
+};
+use std::convert::TryFrom;
+use std::time::Duration;
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_aces_against_kings_preflop() {
+    let players = [cards("AS AH"), cards("KS KH")];
+    let equities = monte_carlo_equity(&players, &[], &[], Budget::Iterations(20_000), 1).unwrap();
+    let (aces, kings) = (&equities[0], &equities[1]);
+    assert_eq!(aces.trials(), 20_000);
+    assert!((aces.equity() - 0.82).abs() < 0.015, "{}", aces.equity());
+    assert!((aces.equity() + kings.equity() - 1.0).abs() < 1e-9);
+    assert!(aces.std_error() > 0.0 && aces.std_error() < 0.005);
+    assert_eq!(aces.wins(), kings.losses());
+    assert_eq!(aces.ties(), kings.ties());
+}
+
+#[test]
+fn test_same_seed_gives_same_result() {
+    let players = [cards("AS KD"), cards("7C 7D"), cards("QH JH")];
+    let board = cards("2H 7S KH");
+    let run = |seed| monte_carlo_equity(&players, &board, &[], Budget::Iterations(2_000), seed);
+    assert_eq!(run(42).unwrap(), run(42).unwrap());
+    assert_ne!(run(42).unwrap(), run(43).unwrap());
+}
+
+#[test]
+fn test_complete_board_is_exact() {
+    let players = [cards("AS KD"), cards("7C 7D")];
+    let board = cards("2H 7S KH 9C 3D");
+    let equities = monte_carlo_equity(&players, &board, &[], Budget::Iterations(10), 0).unwrap();
+    assert_eq!(equities[0].lose(), 1.0);
+    assert_eq!(equities[1].win(), 1.0);
+    assert_eq!(equities[1].std_error(), 0.0);
+}
+
+#[test]
+fn test_split_pots_share_equity() {
+    let players = [cards("2C 3D"), cards("2D 3C"), cards("4H 5H")];
+    let board = cards("10S JS QS KS AS");
+    let equities = monte_carlo_equity(&players, &board, &[], Budget::Iterations(5), 0).unwrap();
+    for equity in &equities {
+        assert_eq!(equity.tie(), 1.0);
+        assert!((equity.equity() - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_dead_cards_are_not_dealt() {
+    // with both remaining aces dead, the aces cannot improve to trips
+    let players = [cards("AS AH"), cards("KS KH")];
+    let board = cards("2C 7D 9S 3H");
+    let dead = cards("AC AD");
+    let equities = monte_carlo_equity(&players, &board, &dead, Budget::Iterations(500), 3).unwrap();
+    let runners = 52 - 10;
+    let kings_win = 2.0 / runners as f64;
+    assert!((equities[1].win() - kings_win).abs() < 0.03);
+}
+
+#[test]
+fn test_time_budget_runs() {
+    let players = [cards("AS AH"), cards("KS KH")];
+    let equities = monte_carlo_equity(
+        &players,
+        &[],
+        &[],
+        Budget::Time(Duration::from_millis(20)),
+        0,
+    )
+    .unwrap();
+    assert!(equities[0].trials() > 0);
+}
+
+#[test]
+fn test_equity_errors() {
+    let board = cards("2C 7D 9S");
+    assert_eq!(
+        monte_carlo_equity(
+            &[cards("AS AH"), cards("KS 2C")],
+            &board,
+            &[],
+            Budget::Iterations(1),
+            0
+        ),
+        Err(PokerError::DuplicateCard {
+            offset: 4,
+            token: "2C".to_string()
+        })
+    );
+    assert!(monte_carlo_equity(
+        &[cards("AS AH KD"), cards("KS KH")],
+        &board,
+        &[],
+        Budget::Iterations(1),
+        0
+    )
+    .is_err());
+    assert!(monte_carlo_equity(
+        &[cards("AS AH"), cards("KS KH")],
+        &cards("2C 7D 9S 3H 4H 5H"),
+        &[],
+        Budget::Iterations(1),
+        0
+    )
+    .is_err());
+}
+
+/// Counts wins and ties for each player over every run-out by evaluating
+/// each hand in full with `evaluate`.
+fn brute_force(
+    players: &[Vec<Card>],
+    board: &[Card],
+    evaluate: impl Fn(&[Card], &[Card]) -> EvaluatedHand,
+) -> Vec<(u64, u64)> {
+    let known: Vec<Card> = players.iter().flatten().chain(board).copied().collect();
+    let live: Vec<Card> = (0..52)
+        .map(|i| Card::try_from(i).unwrap())
+        .filter(|c| !known.contains(c))
+        .collect();
+    let mut counts = vec![(0, 0); players.len()];
+    let mut run_out = |full_board: Vec<Card>| {
+        let hands: Vec<_> = players.iter().map(|p| evaluate(p, &full_board)).collect();
+        let best = hands.iter().max().unwrap();
+        let winners = hands.iter().filter(|h| *h == best).count();
+        for (count, hand) in counts.iter_mut().zip(&hands) {
+            if hand == best {
+                if winners == 1 {
+                    count.0 += 1;
+                } else {
+                    count.1 += 1;
+                }
+            }
+        }
+    };
+    // the brute force checks are all on the flop, two cards to come
+    assert_eq!(board.len(), 3);
+    for (i, turn) in live.iter().enumerate() {
+        for river in &live[i + 1..] {
+            let mut full_board = board.to_vec();
+            full_board.extend([*turn, *river]);
+            run_out(full_board);
+        }
+    }
+    counts
+}
+
+#[test]
+fn test_exhaustive_holdem_matches_evaluation() {
+    let players = vec![cards("AS KD"), cards("7C 7D"), cards("QH JH")];
+    let board = cards("2H 7S KH");
+    let equities = exhaustive_equity(&players, &board, &[]).unwrap();
+    let expected = brute_force(&players, &board, |hole, board| {
+        evaluate_holdem(hole, board).unwrap().hand().clone()
+    });
+    for (equity, (wins, ties)) in equities.iter().zip(expected) {
+        assert_eq!(equity.trials(), 903);
+        assert_eq!((equity.wins(), equity.ties()), (wins, ties));
+    }
+}
+
+#[test]
+fn test_exhaustive_omaha_matches_evaluation() {
+    let players = vec![cards("AS AD KS QD"), cards("JH 10H 9C 8C")];
+    let board = cards("KH QH 2C");
+    let equities = exhaustive_omaha_equity(&players, &board, &[]).unwrap();
+    let expected = brute_force(&players, &board, |hole, board| {
+        evaluate_omaha(hole, board).unwrap().hand().clone()
+    });
+    for (equity, (wins, ties)) in equities.iter().zip(expected) {
+        assert_eq!(equity.trials(), 820);
+        assert_eq!((equity.wins(), equity.ties()), (wins, ties));
+    }
+}
+
+#[test]
+fn test_exhaustive_preflop() {
+    let players = [cards("AS AH"), cards("KS KH")];
+    let equities = exhaustive_equity(&players, &[], &[]).unwrap();
+    assert_eq!(equities[0].trials(), 1_712_304);
+    assert_eq!(equities[0].wins(), equities[1].losses());
+    assert!((equities[0].equity() - 0.82).abs() < 0.01);
+}
+
+#[test]
+fn test_exhaustive_river_and_dead_cards() {
+    let players = [cards("AS AH"), cards("KS KH")];
+    let equities = exhaustive_equity(&players, &cards("2C 7D 9S 3H"), &cards("AC AD KC")).unwrap();
+    // only the last king wins for the kings
+    assert_eq!(equities[0].trials(), 52 - 11);
+    assert_eq!(equities[1].wins(), 1);
+    assert!(exhaustive_omaha_equity(&players, &[], &[]).is_err());
+}
+
+fn range(s: &str) -> Range {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_range_of_single_hands_matches_hand_equity() {
+    let ranges = [range("AsAh"), range("KsKh")];
+    let equities = range_equity(&ranges, &[], &[], Budget::Iterations(20_000), 5).unwrap();
+    let exact = exhaustive_equity(&[cards("AS AH"), cards("KS KH")], &[], &[]).unwrap();
+    let error = equities[0].std_error();
+    assert!((equities[0].equity() - exact[0].equity()).abs() < 4.0 * error);
+}
+
+#[test]
+fn test_range_against_range() {
+    let ranges = [range("AA"), range("KK, QQ")];
+    let equities = range_equity(&ranges, &[], &[], Budget::Iterations(20_000), 5).unwrap();
+    assert!(
+        (equities[0].equity() - 0.81).abs() < 0.02,
+        "{}",
+        equities[0].equity()
+    );
+    assert!((equities[0].equity() + equities[1].equity() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_range_card_removal() {
+    // the only aces left for the range are the club and diamond
+    let ranges = [range("AhAs"), range("AA")];
+    let equities = range_equity(&ranges, &[], &[], Budget::Iterations(2_000), 5).unwrap();
+    assert!(equities[0].tie() > 0.9);
+
+    // only the last two kings can be dealt, making quads on the board
+    let board = cards("KS KH 7D 2C 3H");
+    let ranges = [range("KK"), range("AA")];
+    let equities = range_equity(&ranges, &board, &[], Budget::Iterations(100), 5).unwrap();
+    assert_eq!(equities[0].win(), 1.0);
+}
+
+#[test]
+fn test_range_equity_is_reproducible() {
+    let ranges = [range("TT+, AK"), range("22-99, A2s+, KQo:0.5")];
+    let board = cards("JS 7D 2C");
+    let run = |seed| range_equity(&ranges, &board, &[], Budget::Iterations(1_000), seed);
+    assert_eq!(run(9).unwrap(), run(9).unwrap());
+}
+
+#[test]
+fn test_range_equity_errors() {
+    let board = cards("AS AH 2C");
+    assert_eq!(
+        range_equity(
+            &[range("KK"), range("AA")],
+            &board,
+            &cards("AD"),
+            Budget::Iterations(1),
+            0
+        ),
+        Err(PokerError::EmptyHand {
+            offset: 1,
+            token: String::new()
+        })
+    );
+    assert_eq!(
+        range_equity(
+            &[range("KhKs"), range("KsKh")],
+            &[],
+            &[],
+            Budget::Iterations(1),
+            0
+        ),
+        Err(PokerError::EmptyHand {
+            offset: 1,
+            token: String::new()
+        })
+    );
+    assert!(range_equity(
+        &[range("KK"), range("AA")],
+        &cards("2C 2C 3D"),
+        &[],
+        Budget::Iterations(1),
+        0
+    )
+    .is_err());
+}
+
+#[test]
+fn test_impossible_ranges_fail_quickly() {
+    // five hands each needing an ace, with four aces in the deck
+    let aces: Vec<Range> = (0..5).map(|_| range("A2s+, A2o+")).collect();
+    assert_eq!(
+        range_equity(&aces, &[], &[], Budget::Iterations(1), 0),
+        Err(PokerError::EmptyHand {
+            offset: 4,
+            token: String::new()
+        })
+    );
+
+    // more hands than the deck can deal
+    let everyone: Vec<Range> = (0..27).map(|_| range("22+, K2s+, K2o+")).collect();
+    assert_eq!(
+        range_equity(&everyone, &cards("2C 3D 4H"), &[], Budget::Iterations(1), 0),
+        Err(PokerError::EmptyHand {
+            offset: 24,
+            token: String::new()
+        })
+    );
+}
@@ -0,0 +1,73 @@
+use poker::{try_winning_hands, PokerError};
+
+#[test]
+fn test_valid_hands_parse() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC"]),
+        Ok(Some(vec!["4S 5S 7H 8D JC"]))
+    );
+}
+
+#[test]
+fn test_no_hands_is_not_an_error() {
+    assert_eq!(try_winning_hands(&[]), Ok(None));
+}
+
+#[test]
+fn test_bad_rank_reports_offset_and_token() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 1H 8D JC"]),
+        Err(PokerError::InvalidRank {
+            offset: 6,
+            token: "1H".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_bad_suit_reports_offset_and_token() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D JC", " 4S 5X 7H 8D JC"]),
+        Err(PokerError::InvalidSuit {
+            offset: 4,
+            token: "5X".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_wrong_card_count() {
+    assert_eq!(
+        try_winning_hands(&["4S 5S 7H 8D"]),
+        Err(PokerError::WrongCardCount {
+            offset: 0,
+            token: "4S 5S 7H 8D".to_string(),
+            count: 4
+        })
+    );
+}
+
+#[test]
+fn test_empty_hand() {
+    assert_eq!(
+        try_winning_hands(&["  "]),
+        Err(PokerError::EmptyHand {
+            offset: 0,
+            token: "  ".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_duplicate_card() {
+    let err = try_winning_hands(&["4S 5S 7H 5S JC"]).unwrap_err();
+    assert_eq!(
+        err,
+        PokerError::DuplicateCard {
+            offset: 9,
+            token: "5S".to_string()
+        }
+    );
+    assert_eq!(err.offset(), 9);
+    assert_eq!(err.token(), "5S");
+}
@@ -0,0 +1,82 @@
+use poker::{evaluate, evaluate_cards, Card, HandType, PokerError, Rank};
+
+fn cards(hand: &str) -> Vec<Card> {
+    hand.split_whitespace()
+        .map(|c| c.parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_evaluate_reports_category_and_kickers() {
+    let hand = evaluate("JD QH JS 8D QC").unwrap();
+    assert_eq!(hand.hand_type(), HandType::TwoPair);
+    assert_eq!(hand.tie_breaker(), &[Rank::Queen, Rank::Jack, Rank::Eight]);
+
+    let hand = evaluate("4D AH 3S 2D 5C").unwrap();
+    assert_eq!(hand.hand_type(), HandType::Straight);
+    assert_eq!(hand.tie_breaker(), &[Rank::Five]);
+}
+
+#[test]
+fn test_evaluated_hands_sort() {
+    let mut hands: Vec<_> = ["4S 5H 4C 8D 4H", "2S 4S 5S 6S 7S", "3S 4D 2S 6D 5C"]
+        .iter()
+        .map(|h| evaluate(h).unwrap())
+        .collect();
+    hands.sort();
+    let types: Vec<_> = hands.iter().map(|h| h.hand_type()).collect();
+    assert_eq!(
+        types,
+        vec![HandType::ThreeOfAKind, HandType::Straight, HandType::Flush]
+    );
+}
+
+#[test]
+fn test_split_pots_compare_equal() {
+    assert_eq!(
+        evaluate("3S 4S 5D 6H JH").unwrap(),
+        evaluate("3H 4H 5C 6C JD").unwrap()
+    );
+    assert!(evaluate("3S 5H 6S 8D 7H").unwrap() > evaluate("2S 5D 6D 8C 7S").unwrap());
+}
+
+#[test]
+fn test_evaluate_cards_matches_evaluate() {
+    let hand = "10D JH QS KD AC";
+    assert_eq!(evaluate_cards(&cards(hand)), evaluate(hand));
+}
+
+#[test]
+fn test_evaluate_cards_rejects_bad_input() {
+    assert_eq!(
+        evaluate_cards(&cards("2S 3S 4S 2S 6S")),
+        Err(PokerError::DuplicateCard {
+            offset: 3,
+            token: "2S".to_string()
+        })
+    );
+    assert!(matches!(
+        evaluate_cards(&cards("2S 3S")),
+        Err(PokerError::WrongCardCount { count: 2, .. })
+    ));
+    assert!(matches!(
+        evaluate_cards(&[]),
+        Err(PokerError::EmptyHand { .. })
+    ));
+}
+
+#[test]
+fn test_gapped_hands_are_not_straights() {
+    for hand in &["2S 3H 4D 5C 9S", "6S 7H 8D 9C AS", "5S 7H 8D 9C AS"] {
+        assert_eq!(
+            evaluate(hand).unwrap().hand_type(),
+            HandType::HighCard,
+            "{}",
+            hand
+        );
+    }
+    assert_eq!(
+        evaluate("JS QH KD AC 2S").unwrap().hand_type(),
+        HandType::HighCard
+    );
+}
@@ -0,0 +1,78 @@
+use poker::{evaluate_cards, hand_rank, Card, EvaluatedHand, HandType, HAND_RANKS};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[test]
+fn test_every_five_card_hand_is_classified_correctly() {
+    let deck: Vec<Card> = (0..52).map(|i| Card::try_from(i).unwrap()).collect();
+    let mut counts: HashMap<HandType, usize> = HashMap::new();
+    let mut hand = [deck[0]; 5];
+
+    for a in 0..52 {
+        hand[0] = deck[a];
+        for b in a + 1..52 {
+            hand[1] = deck[b];
+            for c in b + 1..52 {
+                hand[2] = deck[c];
+                for d in c + 1..52 {
+                    hand[3] = deck[d];
+                    for card in &deck[d + 1..] {
+                        hand[4] = *card;
+                        let hand_type = evaluate_cards(&hand).unwrap().hand_type();
+                        *counts.entry(hand_type).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let expected = [
+        (HandType::StraightFlush, 40),
+        (HandType::FourOfAKind, 624),
+        (HandType::FullHouse, 3_744),
+        (HandType::Flush, 5_108),
+        (HandType::Straight, 10_200),
+        (HandType::ThreeOfAKind, 54_912),
+        (HandType::TwoPair, 123_552),
+        (HandType::OnePair, 1_098_240),
+        (HandType::HighCard, 1_302_540),
+    ];
+    for (hand_type, count) in expected.iter() {
+        assert_eq!(counts.get(hand_type), Some(count), "{:?}", hand_type);
+    }
+    assert_eq!(counts.values().sum::<usize>(), 2_598_960);
+}
+
+#[test]
+fn test_lookup_ranks_agree_with_evaluation() {
+    let deck: Vec<Card> = (0..52).map(|i| Card::try_from(i).unwrap()).collect();
+    let mut by_rank: HashMap<u16, EvaluatedHand> = HashMap::new();
+    let mut hand = [deck[0]; 5];
+
+    for a in 0..52 {
+        hand[0] = deck[a];
+        for b in a + 1..52 {
+            hand[1] = deck[b];
+            for c in b + 1..52 {
+                hand[2] = deck[c];
+                for d in c + 1..52 {
+                    hand[3] = deck[d];
+                    for card in &deck[d + 1..] {
+                        hand[4] = *card;
+                        let evaluated = evaluate_cards(&hand).unwrap();
+                        let previous = by_rank
+                            .entry(hand_rank(&hand))
+                            .or_insert_with(|| evaluated.clone());
+                        assert_eq!(*previous, evaluated);
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(by_rank.len(), usize::from(HAND_RANKS));
+    let mut ranks: Vec<u16> = by_rank.keys().copied().collect();
+    ranks.sort_unstable();
+    assert_eq!(ranks, (1..=HAND_RANKS).collect::<Vec<_>>());
+    assert!(ranks.windows(2).all(|w| by_rank[&w[0]] < by_rank[&w[1]]));
+}
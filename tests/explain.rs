@@ -0,0 +1,143 @@
+use poker::{evaluate, explain, HandType, Rank, Reason};
+use std::cmp::Ordering;
+
+fn explain_hands(a: &str, b: &str) -> poker::Explanation {
+    explain(&evaluate(a).unwrap(), &evaluate(b).unwrap())
+}
+
+#[test]
+fn test_different_categories() {
+    let explanation = explain_hands("2S 3S 5S 9S JS", "6S 7H 8D 9C 10S");
+    assert_eq!(explanation.ordering(), Ordering::Greater);
+    assert_eq!(
+        explanation.reason(),
+        Reason::Category {
+            winner: HandType::Flush,
+            loser: HandType::Straight
+        }
+    );
+    assert!(explanation.shared().is_empty());
+    assert_eq!(explanation.to_string(), "Flush beats straight");
+
+    // the winner comes first whichever hand is passed first
+    let explanation = explain_hands("6S 7H 8D 9C 10S", "2S 3S 5S 9S JS");
+    assert_eq!(explanation.ordering(), Ordering::Less);
+    assert_eq!(explanation.to_string(), "Flush beats straight");
+}
+
+#[test]
+fn test_kicker_decides() {
+    let explanation = explain_hands("JS JH 8D 8C QS", "JD JC 8H 8S 2C");
+    assert_eq!(
+        explanation.reason(),
+        Reason::Rank {
+            hand_type: HandType::TwoPair,
+            position: 2,
+            winner: Rank::Queen,
+            loser: Rank::Two
+        }
+    );
+    assert_eq!(explanation.shared(), &[Rank::Jack, Rank::Eight]);
+    assert_eq!(
+        explanation.to_string(),
+        "both two pair Jacks and Eights; Queen kicker beats Two"
+    );
+}
+
+#[test]
+fn test_every_category_by_rank() {
+    let cases = [
+        (
+            "AS 9H 7D 4C 2S",
+            "KS QH 7C 4D 2D",
+            "High card: Ace high beats King high",
+        ),
+        (
+            "AS 9H 7D 4C 3S",
+            "AC 9D 7H 4D 2D",
+            "both high card Ace high with Nine, Seven, Four; Three kicker beats Two",
+        ),
+        (
+            "AS AH 7D 4C 2S",
+            "KS KH 7C 4D 2D",
+            "One pair: Aces beat Kings",
+        ),
+        (
+            "AS AH KD 4C 2S",
+            "AC AD QC 4D 2D",
+            "both one pair Aces; King kicker beats Queen",
+        ),
+        (
+            "JS JH 8D 8C QS",
+            "JD JC 7H 7S AC",
+            "both two pair Jacks; Eights beat Sevens",
+        ),
+        (
+            "7S 7H 7D AC 2S",
+            "6S 6H 6D AD KD",
+            "Three of a kind: Sevens beat Sixes",
+        ),
+        (
+            "6S 7H 8D 9C 10S",
+            "5S 6H 7D 8C 9S",
+            "Straight: Ten high beats Nine high",
+        ),
+        (
+            "AS 9S 7S 4S 3S",
+            "AH 9H 7H 4H 2H",
+            "both flush Ace high with Nine, Seven, Four; Three kicker beats Two",
+        ),
+        (
+            "KS KH KD 4C 4S",
+            "QS QH QD AC AS",
+            "Full house: Kings beat Queens",
+        ),
+        (
+            "9S 9H 9D 9C JS",
+            "9S 9H 9D 9C 8S",
+            "both four of a kind Nines; Jack kicker beats Eight",
+        ),
+        (
+            "6H 7H 8H 9H 10H",
+            "5S 6S 7S 8S 9S",
+            "Straight flush: Ten high beats Nine high",
+        ),
+    ];
+    for (winner, loser, text) in cases {
+        let explanation = explain_hands(winner, loser);
+        assert_eq!(explanation.ordering(), Ordering::Greater, "{}", winner);
+        assert_eq!(explanation.to_string(), text);
+    }
+}
+
+#[test]
+fn test_low_ace_straight() {
+    let explanation = explain_hands("AS 2H 3D 4C 5S", "2S 3H 4D 5C 6S");
+    assert_eq!(explanation.ordering(), Ordering::Less);
+    assert_eq!(
+        explanation.reason(),
+        Reason::Rank {
+            hand_type: HandType::Straight,
+            position: 0,
+            winner: Rank::Six,
+            loser: Rank::Five
+        }
+    );
+    assert_eq!(
+        explanation.to_string(),
+        "Straight: Six high beats Five high"
+    );
+}
+
+#[test]
+fn test_tie() {
+    let explanation = explain_hands("AS 2H 3D 4C 5S", "AH 2C 3S 4D 5H");
+    assert_eq!(explanation.ordering(), Ordering::Equal);
+    assert_eq!(
+        explanation.reason(),
+        Reason::Tie {
+            hand_type: HandType::Straight
+        }
+    );
+    assert_eq!(explanation.to_string(), "tie, both straight Five high");
+}
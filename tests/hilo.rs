@@ -0,0 +1,70 @@
+use poker::{evaluate_hi_lo, evaluate_omaha_hi_lo, hi_lo_winners, split_hi_lo, Card, Rank};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_pot_is_split_between_high_and_low() {
+    let high = "KS KH KD 9C 8S 2H 3D";
+    let low = "AS 2S 4C 6H 7D QC JD";
+    let pot = hi_lo_winners(&[high, low]).unwrap();
+    assert_eq!(pot.high(), &[high]);
+    assert_eq!(pot.low(), &[low]);
+    assert!(pot.has_low());
+    assert_eq!(pot.scooper(), None);
+}
+
+#[test]
+fn test_high_hand_scoops_without_a_qualifying_low() {
+    let high = "KS KH KD 9C 8S 2H 3D";
+    let no_low = "AS 2S 9C 9H JD QC 7C";
+    let pot = hi_lo_winners(&[high, no_low]).unwrap();
+    assert_eq!(pot.high(), &[high]);
+    assert!(pot.low().is_empty());
+    assert_eq!(pot.scooper(), Some(&high));
+}
+
+#[test]
+fn test_wheel_can_scoop() {
+    // a wheel is a straight for high and the best possible low
+    let wheel = "AS 2H 3D 4C 5S KD QC";
+    let pot = hi_lo_winners(&[wheel, "KS KH 7D 6C 8S 2D 3H"]).unwrap();
+    assert_eq!(pot.scooper(), Some(&wheel));
+}
+
+#[test]
+fn test_tied_lows_share_the_low_half() {
+    let first = "AS 2H 3D 4C 6S KD KC";
+    let second = "AH 2D 3C 4S 6H QD QC";
+    let pot = hi_lo_winners(&[first, second]).unwrap();
+    assert_eq!(pot.high(), &[first]);
+    assert_eq!(pot.low().len(), 2);
+    assert_eq!(pot.scooper(), None);
+}
+
+#[test]
+fn test_stud_hand_halves() {
+    let hand = evaluate_hi_lo(&cards("AS 2H 3D 8C 7S 7D 7C")).unwrap();
+    assert_eq!(hand.high().hand().tie_breaker()[0], Rank::Seven);
+    assert_eq!(
+        hand.low().unwrap().hand().ranks(),
+        [Rank::Eight, Rank::Seven, Rank::Three, Rank::Two, Rank::Ace]
+    );
+}
+
+#[test]
+fn test_omaha_eight_split() {
+    let board = cards("3C 5S 8D KD 9H");
+    let hands = vec![
+        evaluate_omaha_hi_lo(&cards("AH 2D QS QC"), &board).unwrap(),
+        evaluate_omaha_hi_lo(&cards("KS KC 10S 10C"), &board).unwrap(),
+        evaluate_omaha_hi_lo(&cards("AS 2C JS JC"), &board).unwrap(),
+    ];
+    let pot = split_hi_lo(&hands).unwrap();
+    assert_eq!(pot.high(), &[1]);
+    let mut low = pot.low().to_vec();
+    low.sort();
+    assert_eq!(low, vec![0, 2]);
+    assert!(split_hi_lo(&[]).is_none());
+}
@@ -0,0 +1,294 @@
+use poker::{Card, HandHistory, PokerError, RecordedAction, Street};
+
+const SHOWDOWN: &str = "\
+PokerStars Hand #1001: Hold'em No Limit ($0.01/$0.02 USD) - 2020/01/01 12:00:00 ET
+Table 'Alpha' 6-max Seat #1 is the button
+Seat 1: Alice ($2 in chips)
+Seat 2: Bob ($1.50 in chips)
+Seat 4: Carol: the Great ($3.00 in chips)
+Bob: posts small blind $0.01
+Carol: the Great: posts big blind $0.02
+*** HOLE CARDS ***
+Dealt to Alice [Ah Kd]
+Alice: raises $0.04 to $0.06
+Bob: calls $0.05
+Carol: the Great: folds
+*** FLOP *** [2c 7d Ks]
+Bob: checks
+Alice: bets $0.10
+Bob: calls $0.10
+Carol: the Great said, \"nh\"
+*** TURN *** [2c 7d Ks] [Qh]
+Bob: checks
+Alice: checks
+*** RIVER *** [2c 7d Ks Qh] [3s]
+Bob: bets $1.34 and is all-in
+Alice: calls $1.34
+*** SHOW DOWN ***
+Bob: shows [Ts 9s] (high card King)
+Alice: shows [Ah Kd] (a pair of Kings)
+Alice collected $2.95 from pot
+*** SUMMARY ***
+Total pot $3.02 | Rake $0.07
+Board [2c 7d Ks Qh 3s]
+Seat 1: Alice (button) showed [Ah Kd] and won ($2.95) with a pair of Kings
+";
+
+const FOLDED: &str = "\
+PokerStars Hand #1002: Tournament #55, $1+$0.10 USD Hold'em No Limit - Level II (15/30) - 2020/01/01 12:05:00 ET
+Table '55 1' 9-max Seat #2 is the button
+Seat 1: Alice (1500 in chips)
+Seat 2: Bob (1,470 in chips)
+Seat 3: Carol (1530 in chips)
+Alice: posts the ante 5
+Bob: posts the ante 5
+Carol: posts the ante 5
+Carol: posts small blind 15
+Alice: posts big blind 30
+*** HOLE CARDS ***
+Bob: folds
+Carol: raises 60 to 90
+Alice: folds
+Uncalled bet (60) returned to Carol
+Carol collected 75 from pot
+Carol: doesn't show hand
+*** SUMMARY ***
+Total pot 75 | Rake 0
+";
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_parse_cash_game() {
+    let history: HandHistory = SHOWDOWN.parse().unwrap();
+    assert_eq!(history.id, "1001");
+    assert_eq!(history.game, "Hold'em No Limit");
+    assert_eq!(history.table, "Alpha");
+    assert_eq!((history.small_blind, history.big_blind), (1, 2));
+    assert_eq!(history.button, 1);
+
+    let names: Vec<&str> = history.seats.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Alice", "Bob", "Carol: the Great"]);
+    assert_eq!(history.seats[2].number, 4);
+    assert_eq!(history.seats[0].chips, 200);
+    assert_eq!(history.seats[1].chips, 150);
+    assert_eq!(history.seats[0].hole_cards, Some(cards("AH KD")));
+    assert_eq!(history.seats[1].hole_cards, Some(cards("10S 9S")));
+    assert_eq!(history.board, cards("2C 7D KS QH 3S"));
+    assert_eq!(history.collected, [(0, 295)]);
+
+    assert_eq!(history.actions.len(), 14);
+    let raise = &history.actions[2];
+    assert_eq!(raise.street, Street::Preflop);
+    assert_eq!(raise.player, 0);
+    assert_eq!(raise.action, RecordedAction::Raise { by: 4, to: 6 });
+    let shove = &history.actions[10];
+    assert_eq!(shove.street, Street::River);
+    assert_eq!(shove.action, RecordedAction::Bet(134));
+    assert!(shove.all_in);
+    assert_eq!(history.actions[12].street, Street::Showdown);
+}
+
+#[test]
+fn test_parse_tournament() {
+    let history: HandHistory = FOLDED.parse().unwrap();
+    assert_eq!(
+        history.game,
+        "Tournament #55, $1+$0.10 USD Hold'em No Limit"
+    );
+    assert_eq!((history.small_blind, history.big_blind), (15, 30));
+    assert_eq!(history.seats[1].chips, 1470);
+    assert_eq!(history.actions[0].action, RecordedAction::Ante(5));
+    assert_eq!(history.returned, [(2, 60)]);
+    assert_eq!(history.collected, [(2, 75)]);
+    assert!(history.board.is_empty());
+}
+
+#[test]
+fn test_contributions() {
+    let history: HandHistory = SHOWDOWN.parse().unwrap();
+    let players = history.contributions().unwrap();
+    let chips: Vec<u64> = players.iter().map(|p| p.chips).collect();
+    assert_eq!(chips, [150, 150, 2]);
+    assert!(players[2].folded);
+    assert_eq!(players[0].hand, Some(cards("AH KD 7D KS QH")));
+
+    let history: HandHistory = FOLDED.parse().unwrap();
+    let chips: Vec<u64> = history
+        .contributions()
+        .unwrap()
+        .iter()
+        .map(|p| p.chips)
+        .collect();
+    assert_eq!(chips, [35, 5, 35]);
+}
+
+#[test]
+fn test_showdown_is_replayed() {
+    let history: HandHistory = SHOWDOWN.parse().unwrap();
+    let settlement = history.settle().unwrap();
+    assert_eq!(settlement.payouts(), &[302, 0, 0]);
+    assert_eq!(history.winners(), [0]);
+    assert_eq!(history.winners_match(), Ok(true));
+
+    let history: HandHistory = FOLDED.parse().unwrap();
+    assert_eq!(history.settle().unwrap().payouts(), &[0, 0, 75]);
+    assert_eq!(history.winners_match(), Ok(true));
+
+    // a log crediting the wrong player fails the check
+    let tampered = SHOWDOWN.replace("Alice collected", "Bob collected");
+    let history: HandHistory = tampered.parse().unwrap();
+    assert_eq!(history.winners(), [1]);
+    assert_eq!(history.winners_match(), Ok(false));
+}
+
+#[test]
+fn test_parse_all() {
+    let text = format!("\u{feff}{}\n\n{}", SHOWDOWN, FOLDED);
+    let histories = HandHistory::parse_all(&text).unwrap();
+    let ids: Vec<&str> = histories.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(ids, ["1001", "1002"]);
+    assert_eq!(HandHistory::parse_all(""), Ok(Vec::new()));
+
+    // offsets are into the whole text
+    let text = format!(
+        "{}\n{}",
+        SHOWDOWN,
+        FOLDED.replace("Bob: folds", "Bob: calls 3O")
+    );
+    let offset = text.find("3O").unwrap();
+    assert_eq!(
+        HandHistory::parse_all(&text),
+        Err(PokerError::InvalidHistory {
+            offset,
+            token: "3O".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_history_errors() {
+    let bad_card = SHOWDOWN.replace("[Ah Kd]\n", "[Ah Kx]\n");
+    let offset = bad_card.find("Kx").unwrap();
+    assert_eq!(
+        bad_card.parse::<HandHistory>(),
+        Err(PokerError::InvalidSuit {
+            offset,
+            token: "Kx".to_string()
+        })
+    );
+
+    let unknown = SHOWDOWN.replace("Dealt to Alice", "Dealt to Dave");
+    let err = unknown.parse::<HandHistory>().unwrap_err();
+    assert_eq!(err.token(), "Dealt to Dave [Ah Kd]");
+    assert_eq!(err.offset(), unknown.find("Dealt").unwrap());
+
+    assert!("Full Tilt Poker Game #1".parse::<HandHistory>().is_err());
+    assert!(SHOWDOWN
+        .replace("($0.01/$0.02 USD)", "(USD)")
+        .parse::<HandHistory>()
+        .is_err());
+}
+
+const DEAD_BLIND: &str = "\
+PokerStars Hand #1003: Hold'em No Limit ($0.01/$0.02 USD) - 2020/01/01 12:10:00 ET
+Table 'Alpha' 6-max Seat #1 is the button
+Seat 1: Alice ($2 in chips)
+Seat 2: Bob ($2 in chips)
+Seat 3: Carl ($2 in chips)
+Seat 4: Dan ($2 in chips)
+Bob: posts small blind $0.01
+Carl: posts big blind $0.02
+Dan: posts small & big blinds $0.03
+*** HOLE CARDS ***
+Dan: raises $0.04 to $0.06
+Alice: folds
+Bob: folds
+Carl: folds
+Uncalled bet ($0.04) returned to Dan
+Dan collected $0.06 from pot
+*** SUMMARY ***
+Total pot $0.06 | Rake $0
+";
+
+#[test]
+fn test_small_and_big_blinds_posted_together() {
+    let history: HandHistory = DEAD_BLIND.parse().unwrap();
+    assert_eq!(
+        history.actions[2].action,
+        RecordedAction::SmallAndBigBlinds(3)
+    );
+    // the dead small blind stays in the pot; the big blind counts towards
+    // the raise
+    let chips: Vec<u64> = history
+        .contributions()
+        .unwrap()
+        .iter()
+        .map(|p| p.chips)
+        .collect();
+    assert_eq!(chips, [0, 1, 2, 3]);
+    assert_eq!(history.settle().unwrap().payouts(), &[0, 0, 0, 6]);
+    assert_eq!(history.winners_match(), Ok(true));
+
+    let straddle = DEAD_BLIND.replace("posts small & big blinds $0.03", "posts straddle $0.04");
+    let offset = straddle.find("Dan: posts").unwrap();
+    assert_eq!(
+        straddle.parse::<HandHistory>(),
+        Err(PokerError::InvalidHistory {
+            offset,
+            token: "Dan: posts straddle $0.04".to_string()
+        })
+    );
+}
+
+const HI_LO: &str = "\
+PokerStars Hand #1004: Omaha Hi/Lo Pot Limit ($0.01/$0.02 USD) - 2020/01/01 12:15:00 ET
+Table 'Beta' 6-max Seat #1 is the button
+Seat 1: Alice ($2 in chips)
+Seat 2: Bob ($2 in chips)
+Alice: posts small blind $0.01
+Bob: posts big blind $0.02
+*** HOLE CARDS ***
+Dealt to Alice [Ah 2h Kd Ks]
+Alice: calls $0.01
+Bob: checks
+*** FLOP *** [3c 4d Qh]
+Bob: bets $0.04
+Alice: calls $0.04
+*** TURN *** [3c 4d Qh] [Js]
+Bob: checks
+Alice: checks
+*** RIVER *** [3c 4d Qh Js] [7c]
+Bob: checks
+Alice: checks
+*** SHOW DOWN ***
+Bob: shows [Qc Qd 9s 8s] (HI: three of a kind, Queens)
+Alice: shows [Ah 2h Kd Ks] (HI: a pair of Kings; LO: 7,4,3,2,A)
+Bob collected $0.06 from pot
+Alice collected $0.06 from pot
+*** SUMMARY ***
+Total pot $0.12 | Rake $0
+";
+
+#[test]
+fn test_hi_lo_is_not_settled() {
+    let history: HandHistory = HI_LO.parse().unwrap();
+    assert_eq!(history.game, "Omaha Hi/Lo Pot Limit");
+    assert_eq!(history.winners(), [0, 1]);
+
+    // the contributions still hold the high hands
+    let players = history.contributions().unwrap();
+    let chips: Vec<u64> = players.iter().map(|p| p.chips).collect();
+    assert_eq!(chips, [6, 6]);
+    assert_eq!(players[1].hand, Some(cards("QC QD QH JS 7C")));
+
+    // but the pot is split high and low, which settling does not do
+    let error = PokerError::InvalidHistory {
+        offset: 0,
+        token: "Omaha Hi/Lo Pot Limit".to_string(),
+    };
+    assert_eq!(history.settle(), Err(error.clone()));
+    assert_eq!(history.winners_match(), Err(error));
+}
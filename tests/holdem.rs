@@ -0,0 +1,68 @@
+use poker::{best_hand, evaluate, evaluate_holdem, Card, HandType, PokerError, Rank};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_holdem_picks_best_five_of_seven() {
+    let best = evaluate_holdem(&cards("AH KH"), &cards("QH JH 2C 10H 3D")).unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::StraightFlush);
+    assert_eq!(best.hand().tie_breaker(), &[Rank::Ace]);
+    assert_eq!(best.cards().to_vec(), cards("AH KH QH JH 10H"));
+}
+
+#[test]
+fn test_holdem_on_the_flop() {
+    let best = evaluate_holdem(&cards("9C 9D"), &cards("9S 4H 4C")).unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::FullHouse);
+    assert_eq!(best.hand().tie_breaker(), &[Rank::Nine, Rank::Four]);
+}
+
+#[test]
+fn test_holdem_can_play_the_board() {
+    let board = cards("10S JS QS KS AS");
+    let first = evaluate_holdem(&cards("2C 3D"), &board).unwrap();
+    let second = evaluate_holdem(&cards("AC AD"), &board).unwrap();
+    assert_eq!(first.hand(), second.hand());
+    assert_eq!(first.cards().to_vec(), board);
+}
+
+#[test]
+fn test_holdem_showdown_with_kickers() {
+    let board = cards("AS 9D 7C 4H 2S");
+    let ace_king = evaluate_holdem(&cards("AD KC"), &board).unwrap();
+    let ace_queen = evaluate_holdem(&cards("AH QC"), &board).unwrap();
+    assert!(ace_king.hand() > ace_queen.hand());
+    assert_eq!(ace_king.hand(), &evaluate("AD KC AS 9D 7C").unwrap());
+}
+
+#[test]
+fn test_best_hand_from_six_cards() {
+    let best = best_hand(&cards("2C 2D 5H 6S 7D 8C")).unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::OnePair);
+    assert_eq!(
+        best.hand().tie_breaker(),
+        &[Rank::Two, Rank::Eight, Rank::Seven, Rank::Six]
+    );
+}
+
+#[test]
+fn test_holdem_rejects_bad_deals() {
+    assert!(matches!(
+        evaluate_holdem(&cards("AH"), &cards("2C 3C 4C")),
+        Err(PokerError::WrongCardCount { count: 1, .. })
+    ));
+    assert!(matches!(
+        evaluate_holdem(&cards("AH KH"), &cards("2C 3C")),
+        Err(PokerError::WrongCardCount { count: 2, .. })
+    ));
+    assert_eq!(
+        evaluate_holdem(&cards("AH KH"), &cards("2C KH 4C")),
+        Err(PokerError::DuplicateCard {
+            offset: 3,
+            token: "KH".to_string()
+        })
+    );
+    assert!(best_hand(&cards("2C 3D 4H 5S 6C 7D 8H 9S")).is_err());
+}
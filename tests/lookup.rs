@@ -0,0 +1,60 @@
+use poker::{best_hand, hand_rank, Card, HAND_RANKS};
+use std::convert::TryFrom;
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+/// Deals `n` distinct cards using a small linear congruential generator.
+fn deal(seed: &mut u64, n: usize) -> Vec<Card> {
+    let mut dealt: Vec<Card> = Vec::with_capacity(n);
+    while dealt.len() < n {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let card = Card::try_from((*seed >> 33) as u8 % 52).unwrap();
+        if !dealt.contains(&card) {
+            dealt.push(card);
+        }
+    }
+    dealt
+}
+
+#[test]
+fn test_extreme_ranks() {
+    assert_eq!(hand_rank(&cards("7S 5H 4D 3C 2S")), 1);
+    assert_eq!(hand_rank(&cards("10S JS QS KS AS")), HAND_RANKS);
+    assert!(hand_rank(&cards("AS 2S 3S 4S 5S")) > hand_rank(&cards("AS AH AD AC KS")));
+    assert!(hand_rank(&cards("AS 2H 3S 4S 5S")) < hand_rank(&cards("2H 3S 4S 5S 6S")));
+}
+
+#[test]
+fn test_seven_card_ranks_agree_with_best_hand() {
+    let mut seed = 7;
+    for _ in 0..2_000 {
+        let first = deal(&mut seed, 7);
+        let second = deal(&mut seed, 7);
+        let by_rank = hand_rank(&first).cmp(&hand_rank(&second));
+        let by_hand = best_hand(&first)
+            .unwrap()
+            .hand()
+            .cmp(best_hand(&second).unwrap().hand());
+        assert_eq!(by_rank, by_hand, "{:?} vs {:?}", first, second);
+    }
+}
+
+#[test]
+fn test_six_card_ranks_agree_with_best_hand() {
+    let mut seed = 6;
+    for _ in 0..2_000 {
+        let hand = deal(&mut seed, 6);
+        let five = best_hand(&hand).unwrap();
+        assert_eq!(hand_rank(&hand), hand_rank(five.cards()));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_four_cards_cannot_be_ranked() {
+    hand_rank(&cards("AS KS QS JS"));
+}
@@ -0,0 +1,130 @@
+use poker::{winning_hands_with, Card, HandType, PokerError, Rank, RankingRules};
+use std::collections::HashSet;
+
+fn test(input: &[&str], expected: &[&str], rules: RankingRules) {
+    assert_eq!(
+        winning_hands_with(input, rules)
+            .expect("This test should produce Some value")
+            .into_iter()
+            .collect::<HashSet<_>>(),
+        expected.iter().copied().collect::<HashSet<_>>()
+    )
+}
+
+#[test]
+fn test_ace_to_five_wheel_is_best() {
+    test(
+        &["AS 2H 3D 4C 5S", "2S 3H 4D 5C 7S", "AH 2D 3C 4S 6H"],
+        &["AS 2H 3D 4C 5S"],
+        RankingRules::AceToFive,
+    )
+}
+
+#[test]
+fn test_ace_to_five_ignores_straights_and_flushes() {
+    test(
+        &["2H 3H 4H 5H 6H", "2S 3D 4C 5S 7H"],
+        &["2H 3H 4H 5H 6H"],
+        RankingRules::AceToFive,
+    )
+}
+
+#[test]
+fn test_ace_to_five_ace_is_low() {
+    test(
+        &["AS 3H 5D 7C 8S", "2S 3D 5C 7S 8H"],
+        &["AS 3H 5D 7C 8S"],
+        RankingRules::AceToFive,
+    )
+}
+
+#[test]
+fn test_ace_to_five_pairs_lose() {
+    test(
+        &["AS AH 2D 3C 4S", "KS QH JD 9C 8S"],
+        &["KS QH JD 9C 8S"],
+        RankingRules::AceToFive,
+    );
+    test(
+        &["AS AH 2D 3C 4S", "2S 2H 3D 4C 5S"],
+        &["AS AH 2D 3C 4S"],
+        RankingRules::AceToFive,
+    );
+}
+
+#[test]
+fn test_ace_to_five_ties() {
+    test(
+        &["AS 2H 3D 4C 6S", "AH 2D 3C 4S 6H"],
+        &["AS 2H 3D 4C 6S", "AH 2D 3C 4S 6H"],
+        RankingRules::AceToFive,
+    )
+}
+
+#[test]
+fn test_deuce_to_seven_number_one() {
+    test(
+        &["2S 3H 4D 5C 7S", "2H 3D 4C 6S 7H", "AS 2D 3C 4H 5D"],
+        &["2S 3H 4D 5C 7S"],
+        RankingRules::DeuceToSeven,
+    )
+}
+
+#[test]
+fn test_deuce_to_seven_ace_is_high() {
+    // A-2-3-4-5 is ace high, not a straight, and loses to king high
+    test(
+        &["AS 2D 3C 4H 5D", "KS 2H 3D 4C 5S"],
+        &["KS 2H 3D 4C 5S"],
+        RankingRules::DeuceToSeven,
+    );
+    let hand = RankingRules::DeuceToSeven
+        .evaluate("AS 2D 3C 4H 5D")
+        .unwrap();
+    assert_eq!(hand.hand_type(), HandType::HighCard);
+    assert_eq!(hand.tie_breaker()[0], Rank::Ace);
+}
+
+#[test]
+fn test_deuce_to_seven_straights_and_flushes_count() {
+    test(
+        &["2S 3H 4D 5C 6S", "2H 4H 5H 7H 8H", "2D 4S 6C 8H 10D"],
+        &["2D 4S 6C 8H 10D"],
+        RankingRules::DeuceToSeven,
+    )
+}
+
+#[test]
+fn test_high_rules_are_the_default() {
+    assert_eq!(RankingRules::default(), RankingRules::High);
+    test(
+        &["2S 3H 4D 5C 7S", "AS AH 2D 3C 4S"],
+        &["AS AH 2D 3C 4S"],
+        RankingRules::High,
+    );
+}
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_razz_best_of_seven() {
+    // the second three would pair, so the eight plays instead
+    let seven = cards("AS 3C 2D KD 3H 7S 8H");
+    let best = RankingRules::AceToFive.best_hand(&seven).unwrap();
+    assert_eq!(best.cards().to_vec(), cards("AS 3C 2D 7S 8H"));
+    assert_eq!(best.hand().hand_type(), HandType::HighCard);
+
+    let best = RankingRules::High.best_hand(&seven).unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::OnePair);
+
+    assert_eq!(
+        RankingRules::AceToFive.best_hand(&seven[..4]),
+        Err(PokerError::WrongCardCount {
+            offset: 0,
+            token: "AS 3C 2D KD".to_string(),
+            count: 4
+        })
+    );
+}
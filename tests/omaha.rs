@@ -0,0 +1,67 @@
+use poker::{evaluate_omaha, evaluate_omaha_hi_lo, Card, HandType, PokerError, Rank};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_omaha_must_use_two_hole_cards() {
+    // four spades on board plus one in hand is not a flush in Omaha
+    let best = evaluate_omaha(&cards("AS KD 7C 2H"), &cards("QS JS 9S 3S 8D")).unwrap();
+    assert_ne!(best.hand().hand_type(), HandType::Flush);
+
+    let best = evaluate_omaha(&cards("AS KS 7C 2H"), &cards("QS JS 9S 3D 8D")).unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::Flush);
+    assert_eq!(best.cards().to_vec(), cards("AS KS QS JS 9S"));
+}
+
+#[test]
+fn test_omaha_must_use_three_board_cards() {
+    // trip queens in hand only play as a pair of queens
+    let best = evaluate_omaha(&cards("QS QD QC 2H"), &cards("AS 9D 5C")).unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::OnePair);
+    assert_eq!(best.hand().tie_breaker()[0], Rank::Queen);
+}
+
+#[test]
+fn test_plo5_and_plo6_hole_cards() {
+    let board = cards("10S JS QD 2C 3H");
+    let plo5 = evaluate_omaha(&cards("AS KD 4C 5H 6H"), &board).unwrap();
+    assert_eq!(plo5.hand().hand_type(), HandType::Straight);
+    let plo6 = evaluate_omaha(&cards("AS KS 4C 5H 6H 9S"), &board).unwrap();
+    assert_eq!(plo6.hand().hand_type(), HandType::Straight);
+    assert!(evaluate_omaha(&cards("AS KS 4C 5H 6H 9S 9D"), &board).is_err());
+    assert!(matches!(
+        evaluate_omaha(&cards("AS KS 4C"), &board),
+        Err(PokerError::WrongCardCount { count: 3, .. })
+    ));
+}
+
+#[test]
+fn test_omaha_hi_lo_finds_both_halves() {
+    let hand = evaluate_omaha_hi_lo(&cards("AH 2D KS KC"), &cards("3C 5S 8D KD 9H")).unwrap();
+    assert_eq!(hand.high().hand().hand_type(), HandType::ThreeOfAKind);
+    let low = hand.low().expect("A-2 makes a low");
+    assert_eq!(
+        low.hand().ranks(),
+        [Rank::Eight, Rank::Five, Rank::Three, Rank::Two, Rank::Ace]
+    );
+}
+
+#[test]
+fn test_omaha_hi_lo_needs_qualifying_low() {
+    let hand = evaluate_omaha_hi_lo(&cards("AH 2D KS KC"), &cards("3C 9S 10D KD 9H")).unwrap();
+    assert!(hand.low().is_none());
+
+    // a low needs two different low hole cards
+    let hand = evaluate_omaha_hi_lo(&cards("AH AD KS KC"), &cards("3C 5S 8D 7D 9H")).unwrap();
+    assert!(hand.low().is_none());
+}
+
+#[test]
+fn test_lower_low_is_better() {
+    let board = cards("3C 4S 8D KD 9H");
+    let wheel = evaluate_omaha_hi_lo(&cards("AH 2D QS QC"), &board).unwrap();
+    let six = evaluate_omaha_hi_lo(&cards("AS 6D JS JC"), &board).unwrap();
+    assert!(wheel.low().unwrap().hand() < six.low().unwrap().hand());
+}
@@ -0,0 +1,325 @@
+use poker::{Action, Blinds, Card, PhhAction, PhhHand, PokerError, Table, TableHand};
+
+const HAND: &str = r#"variant = "NT"
+ante_trimming_status = true
+antes = [0, 0, 0]
+blinds_or_straddles = [1, 2, 0]
+min_bet = 2
+starting_stacks = [200, 200, 200]
+actions = [
+  # Pre-flop
+  "d dh p1 AcKs",
+  "d dh p2 ????",
+  "d dh p3 7h6h",
+  "p3 cbr 6",
+  "p1 cc",
+  "p2 f",
+  # Flop
+  "d db 2c7dKh",
+  "p1 cc",
+  "p3 cbr 10",
+  "p1 cc",
+  "d db Qh",
+  "p1 cc",
+  "p3 cc",
+  "d db Ts",
+  "p1 cc",
+  "p3 cc  # checks it down",
+  "p1 sm AcKs",
+  "p3 sm 7h6h",
+]
+players = ["Alice", "Bob \"B\" Smith", 'Carol']
+hand = 1
+time_zone = 'UTC'
+
+[_custom]
+note = "not read"
+"#;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_read_phh() {
+    let hand: PhhHand = HAND.parse().unwrap();
+    assert_eq!(hand.variant, "NT");
+    assert!(hand.ante_trimming_status);
+    assert_eq!(hand.antes, [0, 0, 0]);
+    assert_eq!(hand.blinds_or_straddles, [1, 2, 0]);
+    assert_eq!(hand.min_bet, 2);
+    assert_eq!(hand.starting_stacks, [200, 200, 200]);
+    assert_eq!(hand.players, ["Alice", "Bob \"B\" Smith", "Carol"]);
+    assert_eq!(hand.finishing_stacks, None);
+
+    assert_eq!(hand.actions.len(), 18);
+    assert_eq!(
+        hand.actions[0],
+        PhhAction::DealHole {
+            player: 0,
+            cards: vec![Some(card("AC")), Some(card("KS"))]
+        }
+    );
+    assert_eq!(
+        hand.actions[1],
+        PhhAction::DealHole {
+            player: 1,
+            cards: vec![None, None]
+        }
+    );
+    assert_eq!(hand.actions[3], PhhAction::BetOrRaise { player: 2, to: 6 });
+    assert_eq!(hand.actions[5], PhhAction::Fold { player: 1 });
+    assert_eq!(
+        hand.actions[13],
+        PhhAction::DealBoard {
+            cards: vec![card("10S")]
+        }
+    );
+    assert_eq!(hand.actions[15], PhhAction::CheckOrCall { player: 2 });
+}
+
+#[test]
+fn test_write_phh() {
+    let hand: PhhHand = HAND.parse().unwrap();
+    let text = hand.to_string();
+    assert!(text.starts_with("variant = \"NT\"\nante_trimming_status = true\n"));
+    assert!(text.contains("  \"d dh p2 ????\",\n"));
+    assert!(text.contains("  \"d db Ts\",\n"));
+    assert!(text.contains("players = [\"Alice\", \"Bob \\\"B\\\" Smith\", \"Carol\"]\n"));
+    assert_eq!(text.parse::<PhhHand>(), Ok(hand));
+}
+
+#[test]
+fn test_actions_round_trip() {
+    for action in [
+        "d dh p1 AcKs",
+        "d dh p6 ????",
+        "d db 2c7dKh",
+        "p2 f",
+        "p3 cc",
+        "p4 cbr 300",
+        "p1 sm",
+        "p1 sm Th??",
+    ] {
+        let parsed: PhhAction = action.parse().unwrap();
+        assert_eq!(parsed.to_string(), action);
+    }
+    for bad in [
+        "p0 f",
+        "p1 raise",
+        "d db Ac??",
+        "d dh p1 AcK",
+        "p1 cbr lots",
+    ] {
+        assert!(bad.parse::<PhhAction>().is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn test_settle_phh() {
+    let hand: PhhHand = HAND.parse().unwrap();
+    let settlement = hand.settle().unwrap();
+    assert_eq!(settlement.payouts(), &[34, 0, 0]);
+
+    // p2 acting out of turn
+    let mut out_of_turn = hand.clone();
+    out_of_turn.actions[4] = PhhAction::CheckOrCall { player: 1 };
+    assert_eq!(
+        out_of_turn.settle(),
+        Err(PokerError::InvalidHistory {
+            offset: 4,
+            token: "p2 cc".to_string()
+        })
+    );
+
+    let mut unfinished = hand.clone();
+    unfinished.actions.truncate(10);
+    assert_eq!(unfinished.settle().unwrap_err().offset(), 10);
+
+    // a hand shown in part cannot be judged
+    let mut partial = hand.clone();
+    partial.actions[17] = "p3 sm 7h??".parse().unwrap();
+    assert_eq!(
+        partial.settle(),
+        Err(PokerError::InvalidHistory {
+            offset: 17,
+            token: "p3 sm 7h??".to_string()
+        })
+    );
+
+    // without shows the hole cards dealt face up are compared
+    let mut unshown = hand.clone();
+    unshown.actions.truncate(16);
+    assert_eq!(unshown.settle().unwrap().payouts(), &[34, 0, 0]);
+
+    let mut straddle = hand;
+    straddle.blinds_or_straddles = vec![1, 2, 4];
+    assert!(straddle.settle().is_err());
+}
+
+#[test]
+fn test_settle_with_min_bet() {
+    let mut hand: PhhHand = HAND.parse().unwrap();
+    hand.antes = vec![5, 5, 5];
+    hand.blinds_or_straddles = vec![0, 0, 0];
+    hand.min_bet = 10;
+    hand.actions.truncate(3);
+    for action in ["p3 cbr 10", "p1 f", "p2 f"] {
+        hand.actions.push(action.parse().unwrap());
+    }
+    assert_eq!(hand.settle().unwrap().payouts(), &[0, 0, 25]);
+
+    // with no big blind the minimum bet still applies
+    hand.actions[3] = PhhAction::BetOrRaise { player: 2, to: 5 };
+    assert_eq!(
+        hand.settle(),
+        Err(PokerError::InvalidHistory {
+            offset: 3,
+            token: "p3 cbr 5".to_string()
+        })
+    );
+}
+
+const MUCKED: &str = r#"variant = "NT"
+antes = [0, 0]
+blinds_or_straddles = [1, 2]
+min_bet = 2
+starting_stacks = [1000, 100]
+actions = [
+  "d dh p1 7c2d",
+  "d dh p2 AsAh",
+  "p2 cc",
+  "p1 cc",
+  "d db Kc8h3d",
+  "p1 cbr 500",
+  "p2 cc",
+  "d db Js",
+  "d db 4c",
+  "p2 sm AsAh",
+  "p1 sm",
+]
+"#;
+
+#[test]
+fn test_uncalled_bet_returned_to_mucked_hand() {
+    let hand: PhhHand = MUCKED.parse().unwrap();
+    let settlement = hand.settle().unwrap();
+    assert_eq!(settlement.payouts(), &[402, 200]);
+
+    // the same as when the losing hand is shown
+    let shown: PhhHand = MUCKED
+        .replace("\"p1 sm\"", "\"p1 sm 7c2d\"")
+        .parse()
+        .unwrap();
+    assert_eq!(shown.settle().unwrap().payouts(), settlement.payouts());
+}
+
+#[test]
+fn test_read_errors() {
+    let missing = HAND.replace("min_bet = 2\n", "");
+    assert_eq!(
+        missing.parse::<PhhHand>(),
+        Err(PokerError::InvalidHistory {
+            offset: 0,
+            token: "min_bet".to_string()
+        })
+    );
+
+    let short = HAND.replace("antes = [0, 0, 0]", "antes = [0, 0]");
+    let err = short.parse::<PhhHand>().unwrap_err();
+    assert_eq!(err.token(), "[0, 0]");
+    assert_eq!(err.offset(), short.find("[0, 0]").unwrap());
+
+    let names = HAND.replace(", 'Carol']", "]");
+    let err = names.parse::<PhhHand>().unwrap_err();
+    assert_eq!(err.offset(), names.find("[\"Alice\"").unwrap());
+
+    let finishing = HAND.replace("[_custom]", "finishing_stacks = [234, 200]\n[_custom]");
+    let err = finishing.parse::<PhhHand>().unwrap_err();
+    assert_eq!(err.token(), "[234, 200]");
+    assert_eq!(err.offset(), finishing.find("[234, 200]").unwrap());
+
+    let fractional = HAND.replace("min_bet = 2", "min_bet = 2.5");
+    assert_eq!(fractional.parse::<PhhHand>().unwrap_err().token(), "2.5");
+
+    let bad_card = HAND.replace("7h6h\",", "7h6x\",");
+    let err = bad_card.parse::<PhhHand>().unwrap_err();
+    assert_eq!(err.token(), "6x");
+    assert_eq!(err.offset(), bad_card.find("6x").unwrap());
+
+    let unknown_player = HAND.replace("\"p2 f\"", "\"p4 f\"");
+    assert_eq!(
+        unknown_player.parse::<PhhHand>().unwrap_err().token(),
+        "\"p4 f\""
+    );
+
+    assert!("variant = \"NT\"\nantes = [0, 0"
+        .parse::<PhhHand>()
+        .is_err());
+}
+
+fn play(stacks: &[u64], script: &[Action]) -> (Table, TableHand) {
+    let blinds = Blinds {
+        small: 1,
+        big: 2,
+        ante: 1,
+    };
+    let mut table = Table::new(stacks, 0, blinds);
+    let mut actions = script.iter();
+    let hand = table
+        .play(17, |hand| {
+            actions.next().copied().unwrap_or_else(|| {
+                if hand.betting().legal_actions().unwrap().can_check() {
+                    Action::Check
+                } else {
+                    Action::Call
+                }
+            })
+        })
+        .unwrap();
+    (table, hand)
+}
+
+#[test]
+fn test_export_table_hand() {
+    let (table, hand) = play(&[100, 100, 100], &[Action::Raise(6)]);
+    let phh = PhhHand::from(&hand);
+    assert_eq!(phh.variant, "NT");
+    assert_eq!(phh.antes, [1, 1, 1]);
+    assert_eq!(phh.blinds_or_straddles, [1, 2, 0]);
+    assert_eq!(phh.starting_stacks, [100, 100, 100]);
+    // the format starts left of the button, seat 0
+    let hole = hand.hole_cards()[1];
+    assert_eq!(
+        phh.actions[0],
+        PhhAction::DealHole {
+            player: 0,
+            cards: vec![Some(hole[0]), Some(hole[1])]
+        }
+    );
+    assert_eq!(phh.actions[3], PhhAction::BetOrRaise { player: 2, to: 6 });
+    let finishing = [table.stacks()[1], table.stacks()[2], table.stacks()[0]];
+    assert_eq!(phh.finishing_stacks, Some(finishing.to_vec()));
+
+    // replaying the record shares the pot out the same way
+    let payouts = hand.settlement().unwrap().payouts().to_vec();
+    let replayed = phh.settle().unwrap();
+    assert_eq!(replayed.payouts(), &[payouts[1], payouts[2], payouts[0]]);
+    assert_eq!(phh.to_string().parse::<PhhHand>(), Ok(phh));
+}
+
+#[test]
+fn test_export_heads_up_all_in() {
+    let (_, hand) = play(&[50, 200], &[Action::AllIn]);
+    let phh = PhhHand::from(&hand);
+    // heads-up the button is the second player and posts the small blind
+    assert_eq!(phh.starting_stacks, [200, 50]);
+    assert_eq!(phh.blinds_or_straddles, [1, 2]);
+    let betting: Vec<String> = phh.actions[2..4].iter().map(|a| a.to_string()).collect();
+    assert_eq!(betting, ["p2 cbr 49", "p1 cc"]);
+    assert!(matches!(phh.actions[4], PhhAction::DealBoard { ref cards } if cards.len() == 3));
+    assert_eq!(phh.actions.len(), 9);
+
+    let payouts = hand.settlement().unwrap().payouts().to_vec();
+    assert_eq!(phh.settle().unwrap().payouts(), &[payouts[1], payouts[0]]);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_lifetimes)]
+
 use poker::winning_hands;
 use std::collections::HashSet;
 
@@ -0,0 +1,106 @@
+use poker::{Card, CardSet, PokerError, Range};
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+fn range(s: &str) -> Range {
+    s.parse().unwrap()
+}
+
+fn invalid(offset: usize, token: &str) -> Result<Range, PokerError> {
+    Err(PokerError::InvalidRange {
+        offset,
+        token: token.to_string(),
+    })
+}
+
+#[test]
+fn test_combo_counts() {
+    assert_eq!(range("TT").len(), 6);
+    assert_eq!(range("AKs").len(), 4);
+    assert_eq!(range("AKo").len(), 12);
+    assert_eq!(range("AK").len(), 16);
+    assert_eq!(range("KA").len(), 16);
+    assert_eq!(range("10T").len(), 6);
+    assert_eq!(range("").len(), 0);
+}
+
+#[test]
+fn test_plus_ranges() {
+    // TT, JJ, QQ, KK, AA
+    assert_eq!(range("TT+").len(), 30);
+    // A2s to AKs
+    assert_eq!(range("A2s+").len(), 12 * 4);
+    assert_eq!(range("A2s+"), range("A2s-AKs"));
+    // 76s, 87s, 98s, T9s, JTs, QJs, KQs, AKs
+    assert_eq!(range("76s+").len(), 8 * 4);
+    assert_eq!(range("76s+"), range("76s-AKs"));
+    // K9o, KTo, KJo, KQo
+    assert_eq!(range("K9o+").len(), 4 * 12);
+    assert_eq!(range("AA+"), range("AA"));
+}
+
+#[test]
+fn test_dash_ranges() {
+    assert_eq!(range("22-55").len(), 4 * 6);
+    assert_eq!(range("55-22"), range("22-55"));
+    assert_eq!(range("A2s-A5s").len(), 4 * 4);
+    assert_eq!(range("A5s-A2s"), range("A2s, A3s, A4s, A5s"));
+    assert_eq!(range("76s-T9s"), range("76s, 87s, 98s, T9s"));
+}
+
+#[test]
+fn test_mixed_range() {
+    let hands = range("AKs, TT+, A2s-A5s, KQo, 76s+, 22-55");
+    // AKs is also part of 76s+
+    assert_eq!(hands.len(), 30 + 16 + 12 + 32 + 24);
+    assert_eq!(hands.weight(card("AS"), card("KS")), 1.0);
+    assert_eq!(hands.weight(card("KS"), card("AS")), 1.0);
+    assert_eq!(hands.weight(card("AS"), card("KH")), 0.0);
+    assert_eq!(hands.weight(card("KH"), card("QS")), 1.0);
+    assert_eq!(hands.weight(card("KH"), card("QH")), 1.0);
+}
+
+#[test]
+fn test_weights() {
+    let hands = range("AKo:0.5, QQ+, KK:0.25");
+    assert_eq!(hands.weight(card("AS"), card("KD")), 0.5);
+    assert_eq!(hands.weight(card("QS"), card("QD")), 1.0);
+    assert_eq!(hands.weight(card("KS"), card("KD")), 0.25);
+    assert_eq!(range("QQ+, KK:0").len(), 12);
+}
+
+#[test]
+fn test_exact_combos() {
+    let hands = range("AhKh, 10s9s, 2C2D");
+    assert_eq!(hands.len(), 3);
+    assert_eq!(hands.weight(card("AH"), card("KH")), 1.0);
+    assert_eq!(hands.weight(card("10S"), card("9S")), 1.0);
+    assert_eq!(hands.iter().next(), Some(([card("2D"), card("2C")], 1.0)));
+}
+
+#[test]
+fn test_card_removal() {
+    let hands = range("AA, AKs");
+    let board: CardSet = "AS 7D 2C".parse().unwrap();
+    let live = hands.without(board);
+    // three of the six aces and three of the four suited AK remain
+    assert_eq!(live.len(), 3 + 3);
+    assert!(live.iter().all(|(combo, _)| !combo.contains(&card("AS"))));
+}
+
+#[test]
+fn test_range_errors() {
+    assert_eq!("AKs, AXs".parse::<Range>(), invalid(5, "AXs"));
+    assert_eq!("TTs".parse::<Range>(), invalid(0, "TTs"));
+    assert_eq!("AK:1.5".parse::<Range>(), invalid(0, "AK:1.5"));
+    assert_eq!("AK,,QQ".parse::<Range>(), invalid(3, ""));
+    assert_eq!("22-AKs".parse::<Range>(), invalid(0, "22-AKs"));
+    assert_eq!("A2s-KQs".parse::<Range>(), invalid(0, "A2s-KQs"));
+    assert_eq!("AsAs".parse::<Range>(), invalid(0, "AsAs"));
+    assert_eq!(
+        "AK, AKz".parse::<Range>().unwrap_err().to_string(),
+        "invalid range \"AKz\" at byte 4"
+    );
+}
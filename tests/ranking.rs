@@ -0,0 +1,82 @@
+use poker::{rank_hands, rank_hands_with, try_rank_hands, PokerError, RankingRules};
+
+#[test]
+fn test_places_in_order() {
+    let hands = [
+        "4S 5S 7H 8D JC",
+        "AS AH 7D 4C 2S",
+        "2S 3S 5S 9S JS",
+        "KS KH 7C 4D 2D",
+    ];
+    assert_eq!(
+        rank_hands(&hands),
+        vec![
+            vec!["2S 3S 5S 9S JS"],
+            vec!["AS AH 7D 4C 2S"],
+            vec!["KS KH 7C 4D 2D"],
+            vec!["4S 5S 7H 8D JC"],
+        ]
+    );
+}
+
+#[test]
+fn test_ties_share_a_place_in_input_order() {
+    let hands = [
+        "4S 5H 6C 8D KH",
+        "2S 4C 7S 9H 10H",
+        "3S 4S 5D 6H JH",
+        "3H 4H 5C 6C JD",
+        "4D 5D 6D 8C KD",
+    ];
+    assert_eq!(
+        rank_hands(&hands),
+        vec![
+            vec!["4S 5H 6C 8D KH", "4D 5D 6D 8C KD"],
+            vec!["3S 4S 5D 6H JH", "3H 4H 5C 6C JD"],
+            vec!["2S 4C 7S 9H 10H"],
+        ]
+    );
+}
+
+#[test]
+fn test_same_references_are_returned() {
+    let owned = ["AS AH 7D 4C 2S".to_string(), "AD AC 7H 4S 2D".to_string()];
+    let hands: Vec<&str> = owned.iter().map(String::as_str).collect();
+    let places = rank_hands(&hands);
+    assert_eq!(places.len(), 1);
+    for (ranked, original) in places[0].iter().zip(&hands) {
+        assert!(std::ptr::eq(*ranked, *original));
+    }
+}
+
+#[test]
+fn test_first_place_matches_winning_hands() {
+    let hands = ["4S 5S 7H 8D JC", "4D 5D 7C 8H JH", "2S 3S 5S 9S QS"];
+    let places = rank_hands(&hands);
+    assert_eq!(Some(places[0].clone()), poker::winning_hands(&hands));
+}
+
+#[test]
+fn test_ranking_under_other_rules() {
+    let hands = ["7S 5H 4D 3C 2S", "AS 2H 3D 4C 5S", "8S 6H 4D 3C 2S"];
+    assert_eq!(
+        rank_hands_with(&hands, RankingRules::DeuceToSeven),
+        vec![
+            vec!["7S 5H 4D 3C 2S"],
+            vec!["8S 6H 4D 3C 2S"],
+            vec!["AS 2H 3D 4C 5S"],
+        ]
+    );
+}
+
+#[test]
+fn test_rank_hands_errors() {
+    assert_eq!(try_rank_hands(&[]), Ok(Vec::new()));
+    assert_eq!(
+        try_rank_hands(&["4S 5S 7H 8D JC", "4S 5S 1H 8D JC"]),
+        Err(PokerError::InvalidRank {
+            offset: 6,
+            token: "1H".to_string()
+        })
+    );
+}
@@ -0,0 +1,164 @@
+use poker::{settle, Card, Contribution, OddChip, PokerError, RankingRules};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+fn player(chips: u64, hand: &str) -> Contribution {
+    Contribution {
+        chips,
+        folded: false,
+        hand: Some(cards(hand)),
+    }
+}
+
+fn folded(chips: u64) -> Contribution {
+    Contribution {
+        chips,
+        folded: true,
+        hand: None,
+    }
+}
+
+const BUTTON_0: OddChip = OddChip::LeftOfButton { button: 0 };
+
+#[test]
+fn test_single_pot() {
+    let players = [
+        player(100, "AS AH 7D 4C 2S"),
+        player(100, "KS KH 7C 4D 2D"),
+        folded(20),
+    ];
+    let settlement = settle(&players, RankingRules::High, BUTTON_0).unwrap();
+    assert_eq!(settlement.pots().len(), 1);
+    assert_eq!(settlement.pots()[0].amount(), 220);
+    assert_eq!(settlement.pots()[0].eligible(), &[0, 1]);
+    assert_eq!(settlement.pots()[0].winners(), &[0]);
+    assert_eq!(settlement.payouts(), &[220, 0, 0]);
+}
+
+#[test]
+fn test_side_pots_for_multiple_all_ins() {
+    // the short stack has the best hand, the middle stack the second best
+    let players = [
+        player(50, "AS AH AD 4C 2S"),
+        player(120, "KS KH KD 4D 3D"),
+        player(200, "QS QH 7C 4H 2D"),
+        folded(30),
+    ];
+    let settlement = settle(&players, RankingRules::High, BUTTON_0).unwrap();
+    let pots = settlement.pots();
+    assert_eq!(pots.len(), 3);
+
+    // main pot: 50 from each live player and the folded 30
+    assert_eq!(pots[0].amount(), 180);
+    assert_eq!(pots[0].eligible(), &[0, 1, 2]);
+    assert_eq!(pots[0].winners(), &[0]);
+    // first side pot: 70 more from each of the two bigger stacks
+    assert_eq!(pots[1].amount(), 140);
+    assert_eq!(pots[1].eligible(), &[1, 2]);
+    assert_eq!(pots[1].winners(), &[1]);
+    // the uncalled 80 goes back to the big stack
+    assert_eq!(pots[2].amount(), 80);
+    assert_eq!(pots[2].eligible(), &[2]);
+    assert_eq!(pots[2].winners(), &[2]);
+
+    assert_eq!(settlement.payouts(), &[180, 140, 80, 0]);
+    assert_eq!(settlement.payouts().iter().sum::<u64>(), 400);
+}
+
+#[test]
+fn test_odd_chips_left_of_button() {
+    let players = [
+        player(33, "AS KH 7D 4C 2S"),
+        player(33, "AD KD 7C 4D 2D"),
+        player(33, "AC KC 7H 4H 2H"),
+        folded(1),
+    ];
+    // 100 chips three ways: 33 each and one odd chip
+    let settlement = settle(
+        &players,
+        RankingRules::High,
+        OddChip::LeftOfButton { button: 1 },
+    )
+    .unwrap();
+    assert_eq!(settlement.pots()[0].winners(), &[0, 1, 2]);
+    assert_eq!(settlement.payouts(), &[33, 33, 34, 0]);
+
+    let settlement = settle(
+        &players,
+        RankingRules::High,
+        OddChip::LeftOfButton { button: 2 },
+    )
+    .unwrap();
+    assert_eq!(settlement.payouts(), &[34, 33, 33, 0]);
+
+    let players = [
+        player(34, "AS KH 7D 4C 2S"),
+        player(34, "AD KD 7C 4D 2D"),
+        player(34, "AC KC 7H 4H 2H"),
+        folded(0),
+    ];
+    // 102 chips three ways split evenly
+    let settlement = settle(&players, RankingRules::High, BUTTON_0).unwrap();
+    assert_eq!(settlement.payouts(), &[34, 34, 34, 0]);
+}
+
+#[test]
+fn test_odd_chip_to_lowest_card() {
+    let players = [
+        player(50, "AS KH 7D 4C 2S"),
+        player(50, "AD KD 7C 4D 2D"),
+        folded(1),
+    ];
+    // the two of diamonds is lower than the two of spades
+    let settlement = settle(&players, RankingRules::High, OddChip::LowestCard).unwrap();
+    assert_eq!(settlement.payouts(), &[50, 51, 0]);
+}
+
+#[test]
+fn test_pots_follow_ranking_rules() {
+    let players = [player(10, "7S 5H 4D 3C 2S"), player(10, "AS AH 7D 4C 2D")];
+    let settlement = settle(&players, RankingRules::DeuceToSeven, BUTTON_0).unwrap();
+    assert_eq!(settlement.payouts(), &[20, 0]);
+}
+
+#[test]
+fn test_last_player_standing_need_not_show() {
+    let players = [
+        Contribution {
+            chips: 10,
+            folded: false,
+            hand: None,
+        },
+        folded(10),
+    ];
+    let settlement = settle(&players, RankingRules::High, BUTTON_0).unwrap();
+    assert_eq!(settlement.payouts(), &[20, 0]);
+}
+
+#[test]
+fn test_settlement_errors() {
+    let players = [
+        player(10, "7S 5H 4D 3C 2S"),
+        Contribution {
+            chips: 10,
+            folded: false,
+            hand: None,
+        },
+    ];
+    assert_eq!(
+        settle(&players, RankingRules::High, BUTTON_0),
+        Err(PokerError::EmptyHand {
+            offset: 1,
+            token: String::new()
+        })
+    );
+    assert!(settle(&[folded(10), folded(5)], RankingRules::High, BUTTON_0).is_err());
+    assert!(settle(
+        &[player(10, "7S 5H 4D 3C"), player(10, "AS AH 7D 4C 2D")],
+        RankingRules::High,
+        BUTTON_0
+    )
+    .is_err());
+}
@@ -0,0 +1,88 @@
+use poker::{winning_hands_with, Card, HandType, PokerError, Rank, RankingRules};
+use std::collections::HashSet;
+
+const SHORT_DECK: RankingRules = RankingRules::ShortDeck {
+    trips_beat_straight: false,
+};
+const TRITON: RankingRules = RankingRules::ShortDeck {
+    trips_beat_straight: true,
+};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+fn test(input: &[&str], expected: &[&str], rules: RankingRules) {
+    assert_eq!(
+        winning_hands_with(input, rules)
+            .expect("This test should produce Some value")
+            .into_iter()
+            .collect::<HashSet<_>>(),
+        expected.iter().copied().collect::<HashSet<_>>()
+    )
+}
+
+#[test]
+fn test_flush_beats_full_house() {
+    test(
+        &["6H 8H 10H QH KH", "AS AD AC 7S 7H"],
+        &["6H 8H 10H QH KH"],
+        SHORT_DECK,
+    );
+    test(
+        &["6H 8H 10H QH KH", "AS AD AC 7S 7H"],
+        &["AS AD AC 7S 7H"],
+        RankingRules::High,
+    );
+}
+
+#[test]
+fn test_ace_plays_low_below_six() {
+    let hand = SHORT_DECK.evaluate("AS 6H 7D 8C 9S").unwrap();
+    assert_eq!(hand.hand_type(), HandType::Straight);
+    assert_eq!(hand.tie_breaker(), &[Rank::Nine]);
+    test(
+        &["AS 6H 7D 8C 9S", "6S 7H 8D 9C 10S"],
+        &["6S 7H 8D 9C 10S"],
+        SHORT_DECK,
+    );
+}
+
+#[test]
+fn test_trips_against_straight_depends_on_rule_set() {
+    let hands = ["7S 8H 9D 10C JS", "QS QH QD 6C 8S"];
+    test(&hands, &["7S 8H 9D 10C JS"], SHORT_DECK);
+    test(&hands, &["QS QH QD 6C 8S"], TRITON);
+}
+
+#[test]
+fn test_short_deck_holdem() {
+    let best = SHORT_DECK
+        .evaluate_holdem(&cards("AH 6D"), &cards("7C 8S 9H KD KC"))
+        .unwrap();
+    assert_eq!(best.hand().hand_type(), HandType::Straight);
+
+    let board = cards("9D 10D KD KS 7C");
+    let flush = SHORT_DECK.evaluate_holdem(&cards("AD 6D"), &board).unwrap();
+    let full_house = SHORT_DECK.evaluate_holdem(&cards("KH 7H"), &board).unwrap();
+    assert_eq!(flush.hand().hand_type(), HandType::Flush);
+    assert_eq!(full_house.hand().hand_type(), HandType::FullHouse);
+    assert_eq!(
+        SHORT_DECK.compare(flush.hand(), full_house.hand()),
+        std::cmp::Ordering::Greater
+    );
+}
+
+#[test]
+fn test_low_cards_are_not_in_the_deck() {
+    assert_eq!(
+        SHORT_DECK.evaluate("AS 6H 7D 8C 5S"),
+        Err(PokerError::InvalidRank {
+            offset: 12,
+            token: "5S".to_string()
+        })
+    );
+    assert!(SHORT_DECK
+        .evaluate_holdem(&cards("2H 6D"), &cards("7C 8S 9H"))
+        .is_err());
+}
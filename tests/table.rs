@@ -0,0 +1,169 @@
+use poker::{evaluate_holdem, Action, ActionError, Blinds, CardSet, Street, Table, TableHand};
+
+const BLINDS: Blinds = Blinds {
+    small: 1,
+    big: 2,
+    ante: 0,
+};
+
+/// Calls or checks every decision.
+fn passive(hand: &TableHand) -> Action {
+    let legal = hand.betting().legal_actions().unwrap();
+    if legal.can_check() {
+        Action::Check
+    } else {
+        Action::Call
+    }
+}
+
+#[test]
+fn test_deal_is_determined_by_seed() {
+    let table = Table::new(&[100, 100, 100, 100], 0, BLINDS);
+    assert_eq!(table.deal(7), table.deal(7));
+    assert_ne!(table.deal(7).hole_cards(), table.deal(8).hole_cards());
+
+    let hand = table.deal(7);
+    assert_eq!(hand.seats(), &[0, 1, 2, 3]);
+    assert_eq!(hand.street(), Street::Preflop);
+    assert!(hand.board().is_empty());
+    assert_eq!(hand.to_act(), Some(3));
+    let dealt: CardSet = hand.hole_cards().iter().flatten().copied().collect();
+    assert_eq!(dealt.len(), 8);
+}
+
+#[test]
+fn test_check_down_reaches_showdown() {
+    let mut table = Table::new(&[100, 100, 100], 0, BLINDS);
+    let hand = table.play(42, passive).unwrap();
+    assert!(hand.is_over());
+    assert_eq!(hand.street(), Street::Showdown);
+    assert_eq!(hand.board().len(), 5);
+    assert_eq!(hand.burned().len(), 3);
+    let seen: CardSet = hand
+        .hole_cards()
+        .iter()
+        .flatten()
+        .chain(hand.board())
+        .chain(hand.burned())
+        .copied()
+        .collect();
+    assert_eq!(seen.len(), 14);
+
+    // the pot goes to the best hand by the evaluator
+    let scores: Vec<_> = hand
+        .hole_cards()
+        .iter()
+        .map(|hole| evaluate_holdem(hole, hand.board()).unwrap().hand().clone())
+        .collect();
+    let best = scores.iter().max().unwrap();
+    let winners: Vec<usize> = (0..3).filter(|p| scores[*p] == *best).collect();
+    let settlement = hand.settlement().unwrap();
+    assert_eq!(settlement.pots()[0].winners(), winners.as_slice());
+    assert_eq!(table.stacks().iter().sum::<u64>(), 300);
+    for player in winners {
+        assert!(table.stacks()[player] > 98);
+    }
+    assert_eq!(table.button(), 1);
+}
+
+#[test]
+fn test_fold_wins_without_board() {
+    let mut table = Table::new(&[100, 100, 100], 0, BLINDS);
+    let hand = table
+        .play(1, |hand| match hand.to_act() {
+            Some(0) => Action::Raise(6),
+            _ => Action::Fold,
+        })
+        .unwrap();
+    assert!(hand.board().is_empty());
+    assert_eq!(hand.showdown_hands(), vec![None, None, None]);
+    assert_eq!(
+        hand.actions(),
+        &[
+            (Street::Preflop, 0, Action::Raise(6)),
+            (Street::Preflop, 1, Action::Fold),
+            (Street::Preflop, 2, Action::Fold),
+        ]
+    );
+    assert_eq!(table.stacks(), &[103, 99, 98]);
+    assert_eq!(table.button(), 1);
+}
+
+#[test]
+fn test_all_in_runs_out_the_board() {
+    let table = Table::new(&[50, 200], 0, BLINDS);
+    let mut hand = table.deal(3);
+    hand.act(Action::AllIn).unwrap();
+    hand.act(Action::Call).unwrap();
+    assert!(hand.is_over());
+    assert_eq!(hand.to_act(), None);
+    assert_eq!(hand.board().len(), 5);
+    let settlement = hand.settlement().unwrap();
+    assert_eq!(settlement.payouts().iter().sum::<u64>(), 100);
+    assert!(hand.showdown_hands().iter().all(Option::is_some));
+}
+
+#[test]
+fn test_side_pots_at_the_table() {
+    let mut table = Table::new(&[20, 50, 200], 0, BLINDS);
+    let hand = table
+        .play(11, |hand| {
+            if hand.street() == Street::Preflop {
+                Action::AllIn
+            } else {
+                passive(hand)
+            }
+        })
+        .unwrap();
+    let settlement = hand.settlement().unwrap();
+    assert_eq!(settlement.pots()[0].amount(), 60);
+    assert_eq!(settlement.pots()[1].amount(), 60);
+    assert_eq!(table.stacks().iter().sum::<u64>(), 270);
+}
+
+#[test]
+fn test_illegal_action_leaves_table_unchanged() {
+    let mut table = Table::new(&[100, 100], 0, BLINDS);
+    let before = table.clone();
+    assert_eq!(
+        table.play(5, |_| Action::Check),
+        Err(ActionError::Illegal(Action::Check))
+    );
+    assert_eq!(table, before);
+}
+
+#[test]
+fn test_empty_seats_are_skipped() {
+    let mut table = Table::new(&[100, 0, 100, 100], 1, BLINDS);
+    let hand = table.deal(9);
+    assert_eq!(hand.seats(), &[0, 2, 3]);
+    // the button passes from the empty seat to seat 2, so seat 3 posts the
+    // small blind and seat 0 the big
+    assert_eq!(hand.betting().button(), 1);
+    assert_eq!(hand.betting().bets(), &[2, 0, 1]);
+
+    table.play(9, passive).unwrap();
+    assert_eq!(table.stacks()[1], 0);
+    assert_eq!(table.button(), 3);
+}
+
+#[test]
+fn test_replay_gives_the_same_hand() {
+    let script = [
+        Action::Raise(6),
+        Action::Call,
+        Action::Check,
+        Action::Bet(10),
+    ];
+    let play = || {
+        let mut table = Table::new(&[100, 100], 0, BLINDS);
+        let mut actions = script.iter();
+        let hand = table
+            .play(21, |hand| {
+                actions.next().copied().unwrap_or_else(|| passive(hand))
+            })
+            .unwrap();
+        (table, hand)
+    };
+    assert_eq!(play(), play());
+}
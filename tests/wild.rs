@@ -0,0 +1,93 @@
+use poker::{winning_hands_wild, HandType, PlayingCard, PokerError, Rank, WildCards};
+
+#[test]
+fn test_joker_parses() {
+    assert_eq!("JK".parse::<PlayingCard>(), Ok(PlayingCard::Joker));
+    assert_eq!("XX".parse::<PlayingCard>(), Ok(PlayingCard::Joker));
+    assert_eq!(PlayingCard::Joker.to_string(), "JK");
+    assert_eq!(
+        "JD".parse::<PlayingCard>(),
+        Ok(PlayingCard::Card("JD".parse().unwrap()))
+    );
+}
+
+#[test]
+fn test_joker_completes_best_hand() {
+    let wild = WildCards::jokers();
+    let hand = wild.evaluate("AS AH AD JK 9C").unwrap();
+    assert_eq!(hand.hand_type(), HandType::FourOfAKind);
+    assert_eq!(hand.tie_breaker(), &[Rank::Ace, Rank::Nine]);
+
+    let hand = wild.evaluate("10S JS QS KS XX").unwrap();
+    assert_eq!(hand.hand_type(), HandType::StraightFlush);
+    assert_eq!(hand.tie_breaker(), &[Rank::Ace]);
+}
+
+#[test]
+fn test_five_of_a_kind() {
+    let wild = WildCards::jokers();
+    let hand = wild.evaluate("7S 7H 7D 7C JK").unwrap();
+    assert_eq!(hand.hand_type(), HandType::FiveOfAKind);
+    assert_eq!(hand.tie_breaker(), &[Rank::Seven]);
+    assert!(hand > wild.evaluate("10S JS QS KS AS").unwrap());
+
+    let hand = wild.evaluate("JK JK JK JK JK").unwrap();
+    assert_eq!(hand.hand_type(), HandType::FiveOfAKind);
+    assert_eq!(hand.tie_breaker(), &[Rank::Ace]);
+}
+
+#[test]
+fn test_deuces_wild() {
+    let wild = WildCards::with_ranks(&[Rank::Two]);
+    let hand = wild.evaluate("2S 2H 5D 6C 8S").unwrap();
+    assert_eq!(hand.hand_type(), HandType::Straight);
+    assert_eq!(hand.tie_breaker(), &[Rank::Nine]);
+
+    let hand = wild.evaluate("2S 2H 5D 5C 8S").unwrap();
+    assert_eq!(hand.hand_type(), HandType::FourOfAKind);
+    assert_eq!(hand.tie_breaker(), &[Rank::Five, Rank::Eight]);
+
+    let hand = wild.evaluate("2S 3S 9S KS 4H").unwrap();
+    assert_eq!(hand.hand_type(), HandType::OnePair);
+    assert_eq!(
+        hand.tie_breaker(),
+        &[Rank::King, Rank::Nine, Rank::Four, Rank::Three]
+    );
+
+    // without the wild rank deuces are just deuces
+    let hand = WildCards::jokers().evaluate("2S 2H 5D 6C 8S").unwrap();
+    assert_eq!(hand.hand_type(), HandType::OnePair);
+}
+
+#[test]
+fn test_wild_card_does_not_duplicate_for_a_flush() {
+    // the joker cannot be a second ace of spades
+    let hand = WildCards::jokers().evaluate("AS KS QS 9S JK").unwrap();
+    assert_eq!(hand.hand_type(), HandType::Flush);
+    assert_eq!(
+        hand.tie_breaker(),
+        &[Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Nine]
+    );
+}
+
+#[test]
+fn test_winning_hands_with_jokers() {
+    let wild = WildCards::jokers();
+    assert_eq!(
+        winning_hands_wild(&["AS AH AD AC KS", "3S 3H JK 3D 3C"], &wild),
+        Some(vec!["3S 3H JK 3D 3C"])
+    );
+}
+
+#[test]
+fn test_wild_hand_errors() {
+    let wild = WildCards::jokers();
+    assert!(wild.evaluate("AS AH JK JK").is_err());
+    assert_eq!(
+        wild.evaluate("AS AH JK AS JK"),
+        Err(PokerError::DuplicateCard {
+            offset: 9,
+            token: "AS".to_string()
+        })
+    );
+}